@@ -0,0 +1,111 @@
+use crate::connectivity::use_reconnect;
+use dioxus::prelude::*;
+use std::fmt;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum RefreshInterval {
+    #[default]
+    Off,
+    FiveSeconds,
+    ThirtySeconds,
+    OneMinute,
+    FiveMinutes,
+}
+
+impl fmt::Display for RefreshInterval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl RefreshInterval {
+    pub const ALL: [RefreshInterval; 5] = [
+        RefreshInterval::Off,
+        RefreshInterval::FiveSeconds,
+        RefreshInterval::ThirtySeconds,
+        RefreshInterval::OneMinute,
+        RefreshInterval::FiveMinutes,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RefreshInterval::Off => "Off",
+            RefreshInterval::FiveSeconds => "5s",
+            RefreshInterval::ThirtySeconds => "30s",
+            RefreshInterval::OneMinute => "1m",
+            RefreshInterval::FiveMinutes => "5m",
+        }
+    }
+
+    pub fn duration(&self) -> Option<Duration> {
+        match self {
+            RefreshInterval::Off => None,
+            RefreshInterval::FiveSeconds => Some(Duration::from_secs(5)),
+            RefreshInterval::ThirtySeconds => Some(Duration::from_secs(30)),
+            RefreshInterval::OneMinute => Some(Duration::from_secs(60)),
+            RefreshInterval::FiveMinutes => Some(Duration::from_secs(300)),
+        }
+    }
+}
+
+/// Calls `on_tick` every time the selected interval elapses, and whenever the
+/// backend comes back after an outage. Changing the interval restarts the
+/// timer, and `Off` stops it.
+pub fn use_auto_refresh(interval: Signal<RefreshInterval>, on_tick: impl FnMut(()) + 'static) {
+    let on_tick = use_callback(on_tick);
+    let mut task: Signal<Option<Task>> = use_signal(|| None);
+
+    use_reconnect(move |_| on_tick.call(()));
+
+    use_effect(move || {
+        let period = interval().duration();
+
+        if let Some(previous) = task.write().take() {
+            previous.cancel();
+        }
+
+        if let Some(period) = period {
+            task.set(Some(spawn(async move {
+                loop {
+                    gloo_timers::future::TimeoutFuture::new(period.as_millis() as u32).await;
+                    on_tick.call(());
+                }
+            })));
+        }
+    });
+}
+
+#[component]
+pub fn AutoRefreshSelect(interval: Signal<RefreshInterval>) -> Element {
+    rsx! {
+        div { display: "flex", align_items: "center", margin_right: "30px",
+            label { color: "#5F6060", font_size: "14px", margin_right: "8px", "Auto-refresh:" }
+            select {
+                background_color: "#2d2d2d",
+                color: "#f8f9fa",
+                border: "1px solid #404040",
+                border_radius: "4px",
+                padding: "6px 12px",
+                outline: "none",
+                value: "{interval()}",
+                onchange: move |e| {
+                    if let Some(selected) = RefreshInterval::ALL
+                        .into_iter()
+                        .find(|choice| choice.as_str() == e.value())
+                    {
+                        interval.set(selected);
+                    }
+                },
+                for choice in RefreshInterval::ALL {
+                    option {
+                        key: "{choice}",
+                        value: "{choice}",
+                        selected: choice == interval(),
+                        "{choice}"
+                    }
+                }
+            }
+        }
+    }
+}
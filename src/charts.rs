@@ -0,0 +1,162 @@
+use crate::indexes::format_bytes;
+use chrono::{TimeZone, Utc};
+use dioxus::prelude::*;
+
+const CHART_WIDTH: f64 = 400.0;
+const CHART_HEIGHT: f64 = 120.0;
+const COLORS: [&str; 6] = [
+    "rgb(25, 118, 210)",
+    "#81c784",
+    "#ffb74d",
+    "#e57373",
+    "#ba68c8",
+    "#4dd0e1",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Unit {
+    Count,
+    PerSecond,
+    BytesPerSecond,
+    Seconds,
+    Ratio,
+}
+
+impl Unit {
+    pub fn format(&self, value: f64) -> String {
+        match self {
+            Unit::Count => format!("{value:.0}"),
+            Unit::PerSecond => format!("{value:.1}/s"),
+            Unit::BytesPerSecond => format!("{}/s", format_bytes(value as u64)),
+            Unit::Seconds if value < 1.0 => format!("{:.1}ms", value * 1000.0),
+            Unit::Seconds => format!("{value:.2}s"),
+            Unit::Ratio => format!("{:.1}%", value * 100.0),
+        }
+    }
+}
+
+/// Points of one line, `x` being a unix timestamp in seconds.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartSeries {
+    pub label: String,
+    pub points: Vec<(f64, f64)>,
+}
+
+fn format_time(at: f64) -> String {
+    Utc.timestamp_opt(at as i64, 0)
+        .single()
+        .map(|at| at.format("%H:%M:%S").to_string())
+        .unwrap_or_default()
+}
+
+/// Time series chart sharing one y axis, starting at zero.
+#[component]
+pub fn LineChart(title: String, unit: Unit, series: Vec<ChartSeries>) -> Element {
+    let points = || series.iter().flat_map(|series| series.points.iter());
+    let start = points().map(|(x, _)| *x).fold(f64::INFINITY, f64::min);
+    let end = points().map(|(x, _)| *x).fold(f64::NEG_INFINITY, f64::max);
+    let max = points()
+        .map(|(_, y)| *y)
+        .filter(|y| y.is_finite())
+        .fold(0.0, f64::max);
+    let has_points = start.is_finite() && end > start;
+    let scale = if max > 0.0 { max } else { 1.0 };
+
+    let polyline = |points: &[(f64, f64)]| {
+        points
+            .iter()
+            .filter(|(_, y)| y.is_finite())
+            .map(|(x, y)| {
+                format!(
+                    "{:.1},{:.1}",
+                    (x - start) / (end - start) * CHART_WIDTH,
+                    CHART_HEIGHT - y / scale * CHART_HEIGHT
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+
+    rsx! {
+        div { flex: 1, min_width: "320px", padding: "12px", background: "#181a1b", border: "1px solid #404040", border_radius: "4px",
+            p { color: "#f8f9fa", font_size: "13px", margin_bottom: "6px", "{title}" }
+            if has_points {
+                svg {
+                    width: "100%",
+                    height: "{CHART_HEIGHT + 20.0}",
+                    view_box: "0 0 {CHART_WIDTH} {CHART_HEIGHT + 20.0}",
+                    preserve_aspect_ratio: "none",
+                    for (i , series) in series.iter().enumerate() {
+                        polyline {
+                            key: "{series.label}",
+                            points: "{polyline(&series.points)}",
+                            fill: "none",
+                            stroke: COLORS[i % COLORS.len()],
+                            stroke_width: "2",
+                        }
+                    }
+                    text {
+                        x: "0",
+                        y: "{CHART_HEIGHT + 14.0}",
+                        fill: "#5F6060",
+                        font_size: "10",
+                        "{format_time(start)}"
+                    }
+                    text {
+                        x: "{CHART_WIDTH}",
+                        y: "{CHART_HEIGHT + 14.0}",
+                        fill: "#5F6060",
+                        font_size: "10",
+                        text_anchor: "end",
+                        "{format_time(end)}"
+                    }
+                }
+                div { display: "flex", flex_wrap: "wrap", gap: "12px", font_size: "12px", margin_top: "4px",
+                    span { color: "#5F6060", "max {unit.format(max)}" }
+                    for (i , series) in series.iter().enumerate() {
+                        span { key: "{series.label}", color: COLORS[i % COLORS.len()],
+                            "{series.label}: "
+                            {
+                                series
+                                    .points
+                                    .last()
+                                    .map(|(_, y)| unit.format(*y))
+                                    .unwrap_or_else(|| "—".to_string())
+                            }
+                        }
+                    }
+                }
+            } else {
+                p { color: "#5F6060", font_size: "12px", "Waiting for samples..." }
+            }
+        }
+    }
+}
+/// Small inline trend line without axes.
+#[component]
+pub fn Sparkline(values: Vec<f64>) -> Element {
+    let max = values.iter().copied().fold(0.0, f64::max);
+    let scale = if max > 0.0 { max } else { 1.0 };
+    let step = 100.0 / (values.len().max(2) - 1) as f64;
+    let points = values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| format!("{:.1},{:.1}", i as f64 * step, 20.0 - value / scale * 20.0))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    rsx! {
+        svg {
+            width: "100",
+            height: "20",
+            view_box: "0 0 100 20",
+            preserve_aspect_ratio: "none",
+            polyline {
+                points: "{points}",
+                fill: "none",
+                stroke: "rgb(25, 118, 210)",
+                stroke_width: "1.5",
+            }
+        }
+    }
+}
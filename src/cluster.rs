@@ -0,0 +1,238 @@
+use crate::Route;
+use crate::auto_refresh::{AutoRefreshSelect, RefreshInterval, use_auto_refresh};
+use crate::health::HealthGrid;
+use crate::quickwit::QuickwitApi;
+use crate::quickwit::cluster::{Cluster, ClusterNode, NodeStatus};
+use dioxus::prelude::*;
+
+fn status_color(status: NodeStatus) -> &'static str {
+    match status {
+        NodeStatus::Ready => "#81c784",
+        NodeStatus::Live => "#ffb74d",
+        NodeStatus::Dead => "#e57373",
+    }
+}
+
+#[component]
+fn RoleBadge(role: String) -> Element {
+    rsx! {
+        span {
+            padding: "2px 6px",
+            margin_right: "4px",
+            border: "1px solid #404040",
+            border_radius: "4px",
+            color: "#b0b0b0",
+            font_size: "12px",
+            white_space: "nowrap",
+            "{role}"
+        }
+    }
+}
+
+#[component]
+fn NodeDetail(cluster: Cluster, node: ClusterNode, version: Option<String>) -> Element {
+    let tasks: Vec<_> = cluster
+        .indexing_tasks()
+        .into_iter()
+        .filter(|task| task.node_id == node.chitchat_id.node_id)
+        .collect();
+    let rows = [
+        ("Node ID", node.chitchat_id.node_id.clone()),
+        ("Status", node.status.as_str().to_string()),
+        ("Roles", node.roles.join(", ")),
+        ("Generation", node.chitchat_id.generation_id.to_string()),
+        (
+            "Gossip address",
+            node.chitchat_id.gossip_advertise_addr.clone(),
+        ),
+        (
+            "gRPC address",
+            node.grpc_advertise_addr.clone().unwrap_or_default(),
+        ),
+        ("Version", version.unwrap_or_else(|| "—".to_string())),
+    ];
+
+    rsx! {
+        div { style: "background: #181a1b; border: 1px solid #404040; border-radius: 4px; padding: 12px 16px; margin-top: 16px;",
+            h2 { style: "font-size: 13px; font-weight: 600; color: #f8f9fa; margin-bottom: 8px;",
+                "{node.chitchat_id.node_id}"
+            }
+            table { style: "width: 100%; border-collapse: collapse; font-size: 13px;",
+                tbody {
+                    for (label , value) in rows {
+                        tr { key: "{label}", style: "border-bottom: 1px solid #2a2a2a;",
+                            td { style: "padding: 6px 12px; color: #b0b0b0; width: 200px;", "{label}" }
+                            td { style: "padding: 6px 12px; color: #f8f9fa; word-break: break-all;", "{value}" }
+                        }
+                    }
+                }
+            }
+
+            h3 { style: "font-size: 13px; font-weight: 600; color: #f8f9fa; margin: 16px 0 8px;",
+                "Indexing pipelines"
+            }
+            if tasks.is_empty() {
+                p { style: "color: #b0b0b0; font-size: 13px;", "None." }
+            } else {
+                ul { style: "color: #b0b0b0; font-size: 13px; padding-left: 16px;",
+                    for task in tasks {
+                        li { key: "{task.index_id}/{task.source_id}",
+                            "{task.index_id} / {task.source_id}: {task.num_pipelines} pipelines"
+                        }
+                    }
+                }
+            }
+
+            h3 { style: "font-size: 13px; font-weight: 600; color: #f8f9fa; margin: 16px 0 8px;",
+                "Gossiped state"
+            }
+            table { style: "width: 100%; border-collapse: collapse; font-size: 12px; font-family: monospace;",
+                tbody {
+                    for (key , value) in node.key_values.iter() {
+                        tr { key: "{key}", style: "border-bottom: 1px solid #2a2a2a;",
+                            td { style: "padding: 4px 12px; color: #b0b0b0; width: 300px; word-break: break-all;",
+                                "{key}"
+                            }
+                            td { style: "padding: 4px 12px; color: #f8f9fa; word-break: break-all;",
+                                "{value}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn ClusterOverview() -> Element {
+    let mut refresh_tick = use_signal(|| 0u64);
+    let refresh_interval = use_signal(|| RefreshInterval::FiveSeconds);
+    let mut selected: Signal<Option<String>> = use_signal(|| None);
+
+    use_auto_refresh(refresh_interval, move |_| refresh_tick += 1);
+
+    let cluster = use_resource(move || {
+        refresh_tick();
+        async { QuickwitApi::cluster().await }
+    });
+    // Only the node answering the API reports its version directly.
+    let version = use_resource(|| async { QuickwitApi::version().await.ok() });
+
+    let node_version = move |node: &ClusterNode, self_node_id: &str| -> Option<String> {
+        node.version().map(str::to_string).or_else(|| {
+            (node.chitchat_id.node_id == self_node_id)
+                .then(|| version().flatten().map(|info| info.build.version))
+                .flatten()
+        })
+    };
+
+    rsx! {
+        div { height: "100%", padding: "20px", overflow_y: "auto",
+            match &*cluster.read() {
+                Some(Ok(cluster)) => {
+                    let nodes = cluster.nodes();
+                    let count = |status: NodeStatus| nodes.iter().filter(|node| node.status == status).count();
+                    let row_background = |node: &ClusterNode| {
+                        if selected().as_deref() == Some(node.chitchat_id.node_id.as_str()) { "#1e1e1e" } else { "transparent" }
+                    };
+                    let selected_node = selected()
+                        .and_then(|node_id| nodes.iter().find(|node| node.chitchat_id.node_id == node_id).cloned());
+                    rsx! {
+                        div {
+                            display: "flex",
+                            align_items: "center",
+                            justify_content: "space-between",
+                            margin_bottom: "16px",
+                            h1 { style: "font-size: 14px; font-weight: 700; color: #f8f9fa;",
+                                "Cluster {cluster.cluster_id}"
+                            }
+                            div { display: "flex", align_items: "center", gap: "12px",
+                                AutoRefreshSelect { interval: refresh_interval }
+                                Link {
+                                    to: Route::NodeConfigViewer {},
+                                    style: "border-radius: 4px; padding: 6px 16px; letter-spacing: 0.02857em; color: white; background-color: black; font-size: 13px; text-decoration: none;",
+                                    "NODE CONFIG"
+                                }
+                            }
+                        }
+                        p { style: "color: #b0b0b0; font-size: 13px; margin-bottom: 12px;",
+                            "{nodes.len()} nodes: {count(NodeStatus::Ready)} ready, {count(NodeStatus::Live)} not ready, {count(NodeStatus::Dead)} dead"
+                        }
+                        HealthGrid {}
+                        table { style: "width: 100%; border-collapse: collapse; background: #181a1b; font-size: 13px;",
+                            thead {
+                                tr { style: "border-bottom: 1px solid #404040;",
+                                    for name in ["Node ID", "Status", "Roles", "Gossip address", "gRPC address", "Generation", "Version"] {
+                                        th {
+                                            key: "{name}",
+                                            style: "padding: 12px 16px; text-align: left; font-weight: 600; color: #f8f9fa;",
+                                            "{name}"
+                                        }
+                                    }
+                                }
+                            }
+                            tbody {
+                                for node in nodes.iter() {
+                                    tr {
+                                        key: "{node.chitchat_id.node_id}",
+                                        style: "border-bottom: 1px solid #2a2a2a; cursor: pointer; background: {row_background(node)};",
+                                        onclick: {
+                                            let node_id = node.chitchat_id.node_id.clone();
+                                            move |_| {
+                                                if selected().as_ref() == Some(&node_id) {
+                                                    selected.set(None);
+                                                } else {
+                                                    selected.set(Some(node_id.clone()));
+                                                }
+                                            }
+                                        },
+                                        td { style: "padding: 8px 16px; color: #f8f9fa;",
+                                            "{node.chitchat_id.node_id}"
+                                            if node.chitchat_id.node_id == cluster.self_node_id {
+                                                span { color: "#5F6060", " (this node)" }
+                                            }
+                                        }
+                                        td { style: "padding: 8px 16px; color: {status_color(node.status)};",
+                                            "{node.status.as_str()}"
+                                        }
+                                        td { style: "padding: 8px 16px;",
+                                            for role in node.roles.iter() {
+                                                RoleBadge { key: "{role}", role: role.clone() }
+                                            }
+                                        }
+                                        td { style: "padding: 8px 16px; color: #b0b0b0;",
+                                            "{node.chitchat_id.gossip_advertise_addr}"
+                                        }
+                                        td { style: "padding: 8px 16px; color: #b0b0b0;",
+                                            "{node.grpc_advertise_addr.clone().unwrap_or_default()}"
+                                        }
+                                        td { style: "padding: 8px 16px; color: #b0b0b0;",
+                                            "{node.chitchat_id.generation_id}"
+                                        }
+                                        td { style: "padding: 8px 16px; color: #b0b0b0;",
+                                            "{node_version(node, &cluster.self_node_id).unwrap_or_else(|| \"—\".to_string())}"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        if let Some(node) = selected_node {
+                            NodeDetail {
+                                cluster: cluster.clone(),
+                                version: node_version(&node, &cluster.self_node_id),
+                                node,
+                            }
+                        }
+                    }
+                }
+                Some(Err(e)) => rsx! {
+                    p { style: "color: #e57373; font-size: 13px;", "{e}" }
+                },
+                None => rsx! {
+                    p { style: "color: #b0b0b0; font-size: 13px;", "Loading..." }
+                },
+            }
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use crate::document::eval;
+use crate::quickwit::{BACKEND_REACHABLE, QuickwitApi};
+use dioxus::prelude::*;
+
+/// How often the backend is probed while it is unreachable.
+const PROBE_INTERVAL_MS: u32 = 5_000;
+
+/// The browser's `navigator.onLine`, kept current by `use_connectivity_watch`.
+static BROWSER_ONLINE: GlobalSignal<bool> = Signal::global(|| true);
+/// Bumped every time connectivity comes back.
+static RECONNECTS: GlobalSignal<u64> = Signal::global(|| 0);
+
+/// Probes the backend until it answers, then tells the pages to refetch.
+async fn wait_for_backend() {
+    loop {
+        if *BROWSER_ONLINE.peek() && QuickwitApi::version().await.is_ok() {
+            *RECONNECTS.write() += 1;
+            return;
+        }
+        gloo_timers::future::TimeoutFuture::new(PROBE_INTERVAL_MS).await;
+    }
+}
+
+/// Follows the browser's online and offline events and probes the backend
+/// while it is unreachable.
+pub fn use_connectivity_watch() {
+    use_hook(|| {
+        spawn(async move {
+            let mut events = eval(
+                r#"
+                dioxus.send(navigator.onLine);
+                window.addEventListener("online", () => dioxus.send(true));
+                window.addEventListener("offline", () => dioxus.send(false));
+                "#,
+            );
+            while let Ok(online) = events.recv::<bool>().await {
+                let was_online = *BROWSER_ONLINE.peek();
+                *BROWSER_ONLINE.write() = online;
+                if online && !was_online {
+                    wait_for_backend().await;
+                }
+            }
+        })
+    });
+
+    let mut probing = use_signal(|| false);
+    use_effect(move || {
+        if !BACKEND_REACHABLE() && !*probing.peek() {
+            probing.set(true);
+            spawn(async move {
+                wait_for_backend().await;
+                probing.set(false);
+            });
+        }
+    });
+}
+
+/// Calls `on_reconnect` each time the backend is reachable again after an
+/// outage, so the caller can refetch what failed meanwhile.
+pub fn use_reconnect(on_reconnect: impl FnMut(()) + 'static) {
+    let on_reconnect = use_callback(on_reconnect);
+    let mut seen = use_signal(|| *RECONNECTS.peek());
+
+    use_effect(move || {
+        let reconnects = RECONNECTS();
+        if reconnects != *seen.peek() {
+            seen.set(reconnects);
+            on_reconnect.call(());
+        }
+    });
+}
+
+/// Banner across the top of every page while the browser is offline or the
+/// backend cannot be reached.
+#[component]
+pub fn ConnectivityBanner() -> Element {
+    let message = if !BROWSER_ONLINE() {
+        "You are offline. Results will refresh once the connection is back."
+    } else if !BACKEND_REACHABLE() {
+        "Quickwit is unreachable. Retrying..."
+    } else {
+        return rsx! {};
+    };
+
+    rsx! {
+        div {
+            style: "padding: 6px 16px; background: rgba(229, 115, 115, 0.15); border-bottom: 1px solid #e57373; color: #e57373; font-size: 13px;",
+            "{message}"
+        }
+    }
+}
@@ -0,0 +1,516 @@
+use crate::Route;
+use crate::metadata::use_metadata_cache;
+use crate::quickwit::QuickwitApi;
+use crate::quickwit::index::{
+    DocMapping, FIELD_TYPES, FieldMapping, INDEX_CONFIG_VERSION, IndexConfig, IndexingSettings,
+    RetentionPolicy, SearchSettings,
+};
+use dioxus::prelude::*;
+
+pub(crate) const INPUT_STYLE: &str = "background: #1e1e1e; border: 1px solid #404040; border-radius: 4px; color: #f8f9fa; padding: 6px 8px; font-size: 13px;";
+const LABEL_STYLE: &str = "color: #b0b0b0; font-size: 13px; width: 220px; flex-shrink: 0;";
+
+const TOKENIZERS: [&str; 5] = [
+    "default",
+    "raw",
+    "en_stem",
+    "whitespace",
+    "chinese_compatible",
+];
+const RECORD_OPTIONS: [&str; 3] = ["basic", "freq", "position"];
+const MODES: [&str; 3] = ["lenient", "strict", "dynamic"];
+const RETENTION_SCHEDULES: [&str; 4] = ["hourly", "daily", "weekly", "monthly"];
+
+fn initial_config() -> IndexConfig {
+    IndexConfig {
+        version: INDEX_CONFIG_VERSION.to_string(),
+        index_id: String::new(),
+        index_uri: String::new(),
+        doc_mapping: DocMapping {
+            mode: Some("lenient".to_string()),
+            field_mappings: vec![
+                FieldMapping {
+                    name: "timestamp".to_string(),
+                    r#type: "datetime".to_string(),
+                    fast: Some(serde_json::Value::Bool(true)),
+                    input_formats: Some(vec!["rfc3339".to_string(), "unix_timestamp".to_string()]),
+                    fast_precision: Some("seconds".to_string()),
+                    ..Default::default()
+                },
+                FieldMapping {
+                    name: "message".to_string(),
+                    r#type: "text".to_string(),
+                    tokenizer: Some("default".to_string()),
+                    record: Some("position".to_string()),
+                    ..Default::default()
+                },
+            ],
+            timestamp_field: "timestamp".to_string(),
+            ..Default::default()
+        },
+        indexing_settings: IndexingSettings::default(),
+        search_settings: SearchSettings {
+            default_search_fields: vec!["message".to_string()],
+            ..Default::default()
+        },
+        retention: None,
+        extra: Default::default(),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PreviewFormat {
+    Yaml,
+    Json,
+}
+
+fn render_preview(config: &IndexConfig, format: PreviewFormat) -> String {
+    match format {
+        PreviewFormat::Yaml => serde_yaml::to_string(config).unwrap_or_default(),
+        PreviewFormat::Json => serde_json::to_string_pretty(config).unwrap_or_default(),
+    }
+}
+
+#[component]
+pub(crate) fn FormRow(label: &'static str, children: Element) -> Element {
+    rsx! {
+        div { display: "flex", align_items: "center", gap: "12px", padding: "6px 0",
+            label { style: LABEL_STYLE, "{label}" }
+            {children}
+        }
+    }
+}
+
+#[component]
+pub(crate) fn Section(title: &'static str, children: Element) -> Element {
+    rsx! {
+        div { margin_bottom: "20px",
+            h2 { style: "font-size: 13px; font-weight: 600; color: #f8f9fa; margin-bottom: 8px;",
+                "{title}"
+            }
+            {children}
+        }
+    }
+}
+
+#[component]
+pub(crate) fn Checkbox(label: String, checked: bool, onchange: EventHandler<bool>) -> Element {
+    rsx! {
+        label { style: "display: flex; align-items: center; gap: 4px; color: #b0b0b0; font-size: 12px; cursor: pointer;",
+            input {
+                r#type: "checkbox",
+                checked,
+                onchange: move |e| onchange.call(e.checked()),
+            }
+            "{label}"
+        }
+    }
+}
+
+fn edit_field(mut config: Signal<IndexConfig>, index: usize, edit: impl FnOnce(&mut FieldMapping)) {
+    if let Some(field) = config.write().doc_mapping.field_mappings.get_mut(index) {
+        edit(field);
+    }
+}
+
+#[component]
+pub(crate) fn FieldRow(mut config: Signal<IndexConfig>, index: usize) -> Element {
+    let Some(field) = config.read().doc_mapping.field_mappings.get(index).cloned() else {
+        return rsx! {};
+    };
+
+    rsx! {
+        div {
+            display: "flex",
+            align_items: "center",
+            flex_wrap: "wrap",
+            gap: "10px",
+            padding: "8px",
+            border_bottom: "1px solid #2a2a2a",
+            input {
+                style: "{INPUT_STYLE} width: 180px;",
+                placeholder: "field name",
+                value: "{field.name}",
+                oninput: move |e| edit_field(config, index, |field| field.name = e.value()),
+            }
+            select {
+                style: INPUT_STYLE,
+                value: "{field.r#type}",
+                onchange: move |e| {
+                    let field_type = e.value();
+                    edit_field(config, index, |field| {
+                        *field = FieldMapping {
+                            name: field.name.clone(),
+                            r#type: field_type,
+                            ..Default::default()
+                        };
+                    })
+                },
+                for field_type in FIELD_TYPES {
+                    option { key: "{field_type}", value: field_type, "{field_type}" }
+                }
+            }
+            Checkbox {
+                label: "indexed",
+                checked: field.indexed.unwrap_or(true),
+                onchange: move |checked| edit_field(config, index, |field| field.indexed = Some(checked)),
+            }
+            Checkbox {
+                label: "stored",
+                checked: field.stored.unwrap_or(true),
+                onchange: move |checked| edit_field(config, index, |field| field.stored = Some(checked)),
+            }
+            Checkbox {
+                label: "fast",
+                checked: field.is_fast(),
+                onchange: move |checked| {
+                    edit_field(config, index, |field| field.fast = Some(serde_json::Value::Bool(checked)))
+                },
+            }
+            if field.r#type == "text" || field.r#type == "json" {
+                select {
+                    style: INPUT_STYLE,
+                    value: field.tokenizer.clone().unwrap_or("default".to_string()),
+                    onchange: move |e| edit_field(config, index, |field| field.tokenizer = Some(e.value())),
+                    for tokenizer in TOKENIZERS {
+                        option { key: "{tokenizer}", value: tokenizer, "tokenizer: {tokenizer}" }
+                    }
+                }
+                select {
+                    style: INPUT_STYLE,
+                    value: field.record.clone().unwrap_or("basic".to_string()),
+                    onchange: move |e| edit_field(config, index, |field| field.record = Some(e.value())),
+                    for record in RECORD_OPTIONS {
+                        option { key: "{record}", value: record, "record: {record}" }
+                    }
+                }
+            }
+            if field.r#type == "datetime" {
+                input {
+                    style: "{INPUT_STYLE} width: 220px;",
+                    placeholder: "input formats, comma separated",
+                    value: field.input_formats.clone().unwrap_or_default().join(","),
+                    oninput: move |e| {
+                        let formats: Vec<String> = e
+                            .value()
+                            .split(',')
+                            .map(|format| format.trim().to_string())
+                            .filter(|format| !format.is_empty())
+                            .collect();
+                        edit_field(config, index, |field| {
+                            field.input_formats = (!formats.is_empty()).then_some(formats);
+                        })
+                    },
+                }
+            }
+            button {
+                r#type: "button",
+                margin_left: "auto",
+                border: "none",
+                background: "none",
+                color: "#e57373",
+                cursor: "pointer",
+                onclick: move |_| {
+                    config.write().doc_mapping.field_mappings.remove(index);
+                },
+                "Remove"
+            }
+        }
+    }
+}
+
+#[component]
+pub(crate) fn SearchSettingsSection(mut config: Signal<IndexConfig>) -> Element {
+    let current = config.read().clone();
+    let text_fields: Vec<String> = current
+        .doc_mapping
+        .field_mappings
+        .iter()
+        .filter(|field| field.r#type == "text" || field.r#type == "json")
+        .map(|field| field.name.clone())
+        .collect();
+
+    rsx! {
+        Section { title: "Search settings",
+            FormRow { label: "Default search fields",
+                div { display: "flex", gap: "12px", flex_wrap: "wrap",
+                    for name in text_fields {
+                        Checkbox {
+                            key: "{name}",
+                            label: "{name}",
+                            checked: current.search_settings.default_search_fields.contains(&name),
+                            onchange: {
+                                let name = name.clone();
+                                move |checked| {
+                                    let mut config = config.write();
+                                    let fields = &mut config.search_settings.default_search_fields;
+                                    fields.retain(|field| *field != name);
+                                    if checked {
+                                        fields.push(name.clone());
+                                    }
+                                }
+                            },
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub(crate) fn RetentionSection(mut config: Signal<IndexConfig>) -> Element {
+    let current = config.read().clone();
+
+    rsx! {
+        Section { title: "Retention",
+            FormRow { label: "Delete old splits",
+                Checkbox {
+                    label: "enable retention",
+                    checked: current.retention.is_some(),
+                    onchange: move |checked: bool| {
+                        config.write().retention = checked
+                            .then(|| RetentionPolicy {
+                                period: "30 days".to_string(),
+                                schedule: "daily".to_string(),
+                            });
+                    },
+                }
+            }
+            if let Some(retention) = current.retention.clone() {
+                FormRow { label: "Period",
+                    input {
+                        style: INPUT_STYLE,
+                        value: "{retention.period}",
+                        oninput: move |e| {
+                            if let Some(retention) = config.write().retention.as_mut() {
+                                retention.period = e.value();
+                            }
+                        },
+                    }
+                }
+                FormRow { label: "Schedule",
+                    select {
+                        style: INPUT_STYLE,
+                        value: "{retention.schedule}",
+                        onchange: move |e| {
+                            if let Some(retention) = config.write().retention.as_mut() {
+                                retention.schedule = e.value();
+                            }
+                        },
+                        for schedule in RETENTION_SCHEDULES {
+                            option { key: "{schedule}", value: schedule, "{schedule}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn CreateIndex() -> Element {
+    let mut config = use_signal(initial_config);
+    let mut preview_format = use_signal(|| PreviewFormat::Yaml);
+    let mut submit_error: Signal<Option<String>> = use_signal(|| None);
+    let mut submitting = use_signal(|| false);
+    let navigator = use_navigator();
+    let metadata = use_metadata_cache();
+
+    let errors = config.read().validate();
+    let current = config.read().clone();
+    let datetime_fields: Vec<String> = current
+        .doc_mapping
+        .field_mappings
+        .iter()
+        .filter(|field| field.r#type == "datetime")
+        .map(|field| field.name.clone())
+        .collect();
+    let can_submit = errors.is_empty() && !submitting();
+
+    rsx! {
+        div { height: "100%", padding: "20px", overflow_y: "auto",
+            div { style: "font-size: 13px; color: #b0b0b0; margin-bottom: 12px;",
+                Link {
+                    to: Route::Indexes {},
+                    style: "color: #b0b0b0; text-decoration: underline; text-decoration-color: #5F6060;",
+                    "Indexes"
+                }
+                " / New index"
+            }
+            h1 { style: "font-size: 14px; font-weight: 700; color: #f8f9fa; margin-bottom: 16px;",
+                "Create index"
+            }
+
+            div { display: "flex", gap: "24px", align_items: "flex-start",
+                div { flex: "1 1 0", min_width: 0,
+                    Section { title: "General",
+                        FormRow { label: "Index id",
+                            input {
+                                style: "{INPUT_STYLE} width: 300px;",
+                                value: "{current.index_id}",
+                                oninput: move |e| config.write().index_id = e.value(),
+                            }
+                        }
+                        FormRow { label: "Index URI (optional)",
+                            input {
+                                style: "{INPUT_STYLE} width: 300px;",
+                                placeholder: "s3://bucket/indexes/my-index",
+                                value: "{current.index_uri}",
+                                oninput: move |e| config.write().index_uri = e.value(),
+                            }
+                        }
+                    }
+
+                    Section { title: "Doc mapping",
+                        FormRow { label: "Mode",
+                            select {
+                                style: INPUT_STYLE,
+                                value: current.doc_mapping.mode.clone().unwrap_or_default(),
+                                onchange: move |e| config.write().doc_mapping.mode = Some(e.value()),
+                                for mode in MODES {
+                                    option { key: "{mode}", value: mode, "{mode}" }
+                                }
+                            }
+                        }
+                        FormRow { label: "Store source",
+                            Checkbox {
+                                label: "keep the original document",
+                                checked: current.doc_mapping.store_source.unwrap_or(false),
+                                onchange: move |checked| config.write().doc_mapping.store_source = Some(checked),
+                            }
+                        }
+                        div { style: "background: #181a1b; border: 1px solid #404040; border-radius: 4px; margin: 8px 0;",
+                            for index in 0..current.doc_mapping.field_mappings.len() {
+                                FieldRow { key: "{index}", config, index }
+                            }
+                            button {
+                                r#type: "button",
+                                margin: "8px",
+                                border: "0",
+                                border_radius: "4px",
+                                padding: "6px 16px",
+                                color: "white",
+                                background_color: "black",
+                                cursor: "pointer",
+                                onclick: move |_| {
+                                    config
+                                        .write()
+                                        .doc_mapping
+                                        .field_mappings
+                                        .push(FieldMapping {
+                                            r#type: "text".to_string(),
+                                            ..Default::default()
+                                        });
+                                },
+                                "ADD FIELD"
+                            }
+                        }
+                        FormRow { label: "Timestamp field",
+                            select {
+                                style: INPUT_STYLE,
+                                value: "{current.doc_mapping.timestamp_field}",
+                                onchange: move |e| config.write().doc_mapping.timestamp_field = e.value(),
+                                option { value: "", "None" }
+                                for name in datetime_fields {
+                                    option { key: "{name}", value: "{name}", "{name}" }
+                                }
+                            }
+                        }
+                    }
+
+                    Section { title: "Indexing settings",
+                        FormRow { label: "Commit timeout (seconds)",
+                            input {
+                                style: INPUT_STYLE,
+                                r#type: "number",
+                                placeholder: "60",
+                                value: current.indexing_settings.commit_timeout_secs.map(|v| v.to_string()).unwrap_or_default(),
+                                oninput: move |e| config.write().indexing_settings.commit_timeout_secs = e.value().parse().ok(),
+                            }
+                        }
+                        FormRow { label: "Split target (documents)",
+                            input {
+                                style: INPUT_STYLE,
+                                r#type: "number",
+                                placeholder: "10000000",
+                                value: current.indexing_settings.split_num_docs_target.map(|v| v.to_string()).unwrap_or_default(),
+                                oninput: move |e| config.write().indexing_settings.split_num_docs_target = e.value().parse().ok(),
+                            }
+                        }
+                    }
+
+                    SearchSettingsSection { config }
+                    RetentionSection { config }
+
+                    if !errors.is_empty() {
+                        ul { style: "color: #e57373; font-size: 13px; margin-bottom: 12px; padding-left: 16px;",
+                            for error in errors.iter() {
+                                li { key: "{error}", "{error}" }
+                            }
+                        }
+                    }
+                    if let Some(error) = submit_error() {
+                        p { style: "color: #e57373; font-size: 13px; margin-bottom: 12px;",
+                            "{error}"
+                        }
+                    }
+                    button {
+                        r#type: "button",
+                        border: "0",
+                        border_radius: "4px",
+                        padding: "6px 16px",
+                        letter_spacing: "0.02857em",
+                        color: if can_submit { "white" } else { "#4C4D4D" },
+                        background_color: if can_submit { "rgb(25, 118, 210)" } else { "#151718" },
+                        cursor: if can_submit { "pointer" } else { "not-allowed" },
+                        disabled: !can_submit,
+                        onclick: move |_| async move {
+                            submitting.set(true);
+                            submit_error.set(None);
+                            let request = config.read().clone();
+                            match QuickwitApi::create_index(&request).await {
+                                Ok(index) => {
+                                    metadata.invalidate();
+                                    navigator
+                                        .push(Route::IndexDetail {
+                                            index_id: index.index_config.index_id,
+                                        });
+                                }
+                                Err(e) => submit_error.set(Some(e.to_string())),
+                            }
+                            submitting.set(false);
+                        },
+                        if submitting() {
+                            "CREATING..."
+                        } else {
+                            "CREATE INDEX"
+                        }
+                    }
+                }
+
+                div { flex: "1 1 0", min_width: 0, position: "sticky", top: 0,
+                    div { display: "flex", gap: "8px", margin_bottom: "8px",
+                        for (format , name) in [(PreviewFormat::Yaml, "YAML"), (PreviewFormat::Json, "JSON")] {
+                            button {
+                                key: "{name}",
+                                r#type: "button",
+                                border: "none",
+                                border_bottom: if preview_format() == format { "2px solid rgb(25, 118, 210)" } else { "2px solid transparent" },
+                                background: "none",
+                                color: if preview_format() == format { "#f8f9fa" } else { "#b0b0b0" },
+                                padding: "6px 12px",
+                                cursor: "pointer",
+                                onclick: move |_| preview_format.set(format),
+                                "{name}"
+                            }
+                        }
+                    }
+                    pre { style: "background: #181a1b; border: 1px solid #404040; border-radius: 4px; padding: 12px; color: #f8f9fa; font-size: 12px; overflow: auto; white-space: pre;",
+                        "{render_preview(&current, preview_format())}"
+                    }
+                }
+            }
+        }
+    }
+}
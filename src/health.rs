@@ -0,0 +1,205 @@
+use crate::Route;
+use crate::quickwit::QuickwitApi;
+use crate::quickwit::cluster::{HealthStatus, NodeHealth, NodeStatus};
+use chrono::Utc;
+use dioxus::prelude::*;
+
+const POLL_INTERVAL_MS: u32 = 15_000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeHealthEntry {
+    pub node_id: String,
+    pub node_url: String,
+    pub health: NodeHealth,
+    /// When the status last changed, in seconds.
+    pub changed_at: i64,
+    pub checked_at: i64,
+}
+
+impl NodeHealthEntry {
+    pub fn status(&self) -> HealthStatus {
+        self.health.status()
+    }
+}
+
+/// Latest health of every cluster member, shared by the header badge and the
+/// cluster page.
+#[derive(Clone, Copy)]
+pub struct HealthMonitor {
+    pub nodes: Signal<Vec<NodeHealthEntry>>,
+}
+
+impl HealthMonitor {
+    /// Nodes known to be unwell. Nodes the browser cannot reach are counted
+    /// apart, since that says nothing about the node itself.
+    pub fn num_degraded(&self) -> usize {
+        self.count(|status| matches!(status, HealthStatus::NotReady | HealthStatus::Down))
+    }
+
+    pub fn num_unreachable(&self) -> usize {
+        self.count(|status| status == HealthStatus::Unreachable)
+    }
+
+    fn count(&self, predicate: impl Fn(HealthStatus) -> bool) -> usize {
+        self.nodes
+            .read()
+            .iter()
+            .filter(|entry| predicate(entry.status()))
+            .count()
+    }
+}
+
+async fn poll(mut nodes: Signal<Vec<NodeHealthEntry>>) {
+    let Ok(cluster) = QuickwitApi::cluster().await else {
+        return;
+    };
+
+    // Probed concurrently, each with a timeout, so one unresponsive node
+    // does not hold up the others.
+    let probes = cluster.nodes().into_iter().map(|node| {
+        let node_url = QuickwitApi::node_url(&node, &cluster.self_node_id);
+        async move {
+            let health = if node.status == NodeStatus::Dead {
+                NodeHealth {
+                    live: Some(false),
+                    ..NodeHealth::default()
+                }
+            } else {
+                QuickwitApi::node_health(&node_url).await
+            };
+            (node.chitchat_id.node_id, node_url, health)
+        }
+    });
+    let probed = futures::future::join_all(probes).await;
+
+    let now = Utc::now().timestamp();
+    let polled = probed
+        .into_iter()
+        .map(|(node_id, node_url, health)| {
+            let changed_at = nodes
+                .read()
+                .iter()
+                .find(|entry| entry.node_id == node_id)
+                .filter(|entry| entry.status() == health.status())
+                .map_or(now, |entry| entry.changed_at);
+            NodeHealthEntry {
+                node_id,
+                node_url,
+                health,
+                changed_at,
+                checked_at: now,
+            }
+        })
+        .collect();
+    nodes.set(polled);
+}
+
+/// Starts polling node health and makes the results available to every
+/// component below the caller.
+pub fn use_health_monitor_provider() -> HealthMonitor {
+    let monitor = use_context_provider(|| HealthMonitor {
+        nodes: Signal::new(Vec::new()),
+    });
+    use_hook(|| {
+        spawn(async move {
+            loop {
+                poll(monitor.nodes).await;
+                gloo_timers::future::TimeoutFuture::new(POLL_INTERVAL_MS).await;
+            }
+        })
+    });
+    monitor
+}
+
+pub fn use_health_monitor() -> HealthMonitor {
+    use_context()
+}
+
+fn format_elapsed(seconds: i64) -> String {
+    match seconds.max(0) {
+        s if s < 60 => format!("{s}s"),
+        s if s < 3600 => format!("{}m", s / 60),
+        s if s < 86400 => format!("{}h", s / 3600),
+        s => format!("{}d", s / 86400),
+    }
+}
+
+fn status_color(status: HealthStatus) -> &'static str {
+    match status {
+        HealthStatus::Healthy => "#81c784",
+        HealthStatus::NotReady => "#ffb74d",
+        HealthStatus::Down => "#e57373",
+        HealthStatus::Unreachable => "#5F6060",
+    }
+}
+
+fn format_probe(value: Option<bool>) -> &'static str {
+    match value {
+        Some(true) => "yes",
+        Some(false) => "no",
+        None => "no answer",
+    }
+}
+
+/// Header badge counting nodes that are not healthy, linking to the cluster
+/// page. Hidden while every node is healthy.
+#[component]
+pub fn HealthBadge() -> Element {
+    let monitor = use_health_monitor();
+    let num_degraded = monitor.num_degraded();
+    let num_unreachable = monitor.num_unreachable();
+
+    rsx! {
+        if num_unreachable > 0 {
+            Link {
+                to: Route::ClusterOverview {},
+                style: "margin-left: 12px; padding: 2px 8px; border-radius: 10px; border: 1px solid #5F6060; color: #b0b0b0; font-size: 12px; font-weight: 400; text-decoration: none;",
+                title: "The browser got no answer from these nodes; their address may not be reachable from here or may not allow CORS.",
+                "{num_unreachable} unreachable from browser"
+            }
+        }
+        if num_degraded > 0 {
+            Link {
+                to: Route::ClusterOverview {},
+                style: "margin-left: 12px; padding: 2px 8px; border-radius: 10px; background: #c62828; color: white; font-size: 12px; text-decoration: none;",
+                if num_degraded == 1 {
+                    "1 node degraded"
+                } else {
+                    "{num_degraded} nodes degraded"
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn HealthGrid() -> Element {
+    let monitor = use_health_monitor();
+    let now = Utc::now().timestamp();
+
+    rsx! {
+        div { style: "display: grid; grid-template-columns: repeat(auto-fill, minmax(240px, 1fr)); gap: 12px; margin-bottom: 16px;",
+            if monitor.nodes.read().is_empty() {
+                p { style: "color: #b0b0b0; font-size: 13px;", "Checking node health..." }
+            }
+            for entry in monitor.nodes.read().iter() {
+                div {
+                    key: "{entry.node_id}",
+                    style: "background: #181a1b; border: 1px solid #404040; border-left: 4px solid {status_color(entry.status())}; border-radius: 4px; padding: 10px 12px; font-size: 13px;",
+                    div { display: "flex", justify_content: "space-between", margin_bottom: "6px",
+                        span { color: "#f8f9fa", font_weight: "600", "{entry.node_id}" }
+                        span { color: status_color(entry.status()), "{entry.status().as_str()}" }
+                    }
+                    p { color: "#b0b0b0", "live: {format_probe(entry.health.live)}, ready: {format_probe(entry.health.ready)}" }
+                    p { color: "#b0b0b0",
+                        "version: {entry.health.version.clone().unwrap_or_else(|| \"—\".to_string())}"
+                    }
+                    p { color: "#5F6060", font_size: "12px", margin_top: "4px",
+                        "{entry.status().as_str()} for {format_elapsed(now - entry.changed_at)}, checked {format_elapsed(now - entry.checked_at)} ago"
+                    }
+                    p { color: "#5F6060", font_size: "12px", word_break: "break-all", "{entry.node_url}" }
+                }
+            }
+        }
+    }
+}
@@ -36,6 +36,69 @@ pub fn Database() -> Element {
     }
 }
 
+#[component]
+pub fn Server() -> Element {
+    rsx! {
+        svg {
+            view_box: "0 0 24 24",
+            width: "18",
+            height: "18",
+            fill: "none",
+            stroke: "currentColor",
+            stroke_linecap: "round",
+            stroke_linejoin: "round",
+            rect {
+                x: "2",
+                y: "3",
+                width: "20",
+                height: "7",
+                rx: "2",
+            }
+            rect {
+                x: "2",
+                y: "14",
+                width: "20",
+                height: "7",
+                rx: "2",
+            }
+            path { d: "M6 6.5h.01M6 17.5h.01" }
+        }
+    }
+}
+
+#[component]
+pub fn Chart() -> Element {
+    rsx! {
+        svg {
+            view_box: "0 0 24 24",
+            width: "18",
+            height: "18",
+            fill: "none",
+            stroke: "currentColor",
+            stroke_linecap: "round",
+            stroke_linejoin: "round",
+            path { d: "M3 3v18h18" }
+            path { d: "m7 15 4-4 3 3 6-6" }
+        }
+    }
+}
+
+#[component]
+pub fn Activity() -> Element {
+    rsx! {
+        svg {
+            view_box: "0 0 24 24",
+            width: "18",
+            height: "18",
+            fill: "none",
+            stroke: "currentColor",
+            stroke_linecap: "round",
+            stroke_linejoin: "round",
+            path { d: "M22 12h-4l-3 9L9 3l-3 9H2" }
+        }
+    }
+}
+
 pub fn FilledArrowRight() -> Element {
     rsx! {
         svg {
@@ -0,0 +1,305 @@
+use crate::indexes::format_bytes;
+use crate::metadata::use_metadata_cache;
+use crate::quickwit::QuickwitApi;
+use crate::quickwit::index::{Split, SplitState};
+use dioxus::prelude::*;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IndexAction {
+    Delete,
+    Clear,
+    DeleteSplits,
+}
+
+impl fmt::Display for IndexAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IndexAction::Delete => write!(f, "Delete index"),
+            IndexAction::Clear => write!(f, "Clear index"),
+            IndexAction::DeleteSplits => write!(f, "Delete splits"),
+        }
+    }
+}
+
+impl IndexAction {
+    pub const ALL: [IndexAction; 3] = [
+        IndexAction::Clear,
+        IndexAction::DeleteSplits,
+        IndexAction::Delete,
+    ];
+
+    async fn run(self, index_id: &str, split_ids: &[String]) -> anyhow::Result<()> {
+        match self {
+            IndexAction::Delete => QuickwitApi::delete_index(index_id, false).await.map(|_| ()),
+            IndexAction::Clear => QuickwitApi::clear_index(index_id).await,
+            IndexAction::DeleteSplits => {
+                QuickwitApi::mark_splits_for_deletion(index_id, split_ids).await
+            }
+        }
+    }
+}
+
+#[component]
+fn SplitPicker(splits: Vec<Split>, mut selected: Signal<Vec<String>>) -> Element {
+    rsx! {
+        div { max_height: "240px", overflow_y: "auto", border: "1px solid #404040", margin: "8px 0",
+            table { style: "width: 100%; border-collapse: collapse; font-size: 12px;",
+                tbody {
+                    for split in splits {
+                        tr {
+                            key: "{split.split_id}",
+                            style: "border-bottom: 1px solid #2a2a2a;",
+                            td { style: "padding: 4px 8px;",
+                                input {
+                                    r#type: "checkbox",
+                                    checked: selected.read().contains(&split.split_id),
+                                    onchange: {
+                                        let split_id = split.split_id.clone();
+                                        move |e: FormEvent| {
+                                            let mut selected = selected.write();
+                                            selected.retain(|id| *id != split_id);
+                                            if e.checked() {
+                                                selected.push(split_id.clone());
+                                            }
+                                        }
+                                    },
+                                }
+                            }
+                            td { style: "padding: 4px 8px; color: #f8f9fa; font-family: monospace;",
+                                "{split.split_id}"
+                            }
+                            td { style: "padding: 4px 8px; color: #b0b0b0;", "{split.split_state.as_str()}" }
+                            td { style: "padding: 4px 8px; color: #b0b0b0;", "{split.num_docs} docs" }
+                            td { style: "padding: 4px 8px; color: #b0b0b0;",
+                                "{format_bytes(split.uncompressed_docs_size_in_bytes)}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Confirmation dialog for destructive index operations. The action only
+/// runs once the index id has been typed in full.
+#[component]
+pub fn IndexActionDialog(
+    index_id: String,
+    action: IndexAction,
+    on_close: EventHandler<()>,
+    on_done: EventHandler<()>,
+) -> Element {
+    let mut confirmation = use_signal(String::new);
+    let selected: Signal<Vec<String>> = use_signal(Vec::new);
+    let mut running = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    let metadata = use_metadata_cache();
+
+    let description = use_resource({
+        let index_id = index_id.clone();
+        move || {
+            let index_id = index_id.clone();
+            async move { QuickwitApi::index_describe(&index_id).await }
+        }
+    });
+    let dry_run = use_resource({
+        let index_id = index_id.clone();
+        move || {
+            let index_id = index_id.clone();
+            async move {
+                match action {
+                    IndexAction::Delete => Some(QuickwitApi::delete_index(&index_id, true).await),
+                    _ => None,
+                }
+            }
+        }
+    });
+    let splits = use_resource({
+        let index_id = index_id.clone();
+        move || {
+            let index_id = index_id.clone();
+            async move {
+                match action {
+                    IndexAction::DeleteSplits => QuickwitApi::index_splits(&index_id)
+                        .await
+                        .map(|response| {
+                            response
+                                .splits
+                                .into_iter()
+                                .filter(|split| split.split_state != SplitState::MarkedForDeletion)
+                                .collect::<Vec<_>>()
+                        })
+                        .ok(),
+                    _ => None,
+                }
+            }
+        }
+    });
+
+    let selected_splits: Vec<Split> = splits()
+        .flatten()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|split| selected.read().contains(&split.split_id))
+        .collect();
+    let confirmed = confirmation() == index_id
+        && (action != IndexAction::DeleteSplits || !selected_splits.is_empty());
+    let can_run = confirmed && !running();
+    let target_index_id = index_id.clone();
+
+    rsx! {
+        div {
+            position: "fixed",
+            top: "0",
+            right: "0",
+            bottom: "0",
+            left: "0",
+            z_index: 1400,
+            background: "rgba(0, 0, 0, 0.6)",
+            display: "flex",
+            align_items: "center",
+            justify_content: "center",
+            cursor: "default",
+            onclick: |e| e.stop_propagation(),
+            div {
+                width: "640px",
+                max_height: "80vh",
+                overflow_y: "auto",
+                padding: "20px",
+                background: "#1e1e1e",
+                border: "1px solid #404040",
+                border_radius: "4px",
+                color: "#f8f9fa",
+                font_size: "13px",
+                font_weight: "400",
+                text_align: "left",
+                h2 { style: "font-size: 14px; font-weight: 700; margin-bottom: 12px;",
+                    "{action}: {index_id}"
+                }
+
+                match &*description.read() {
+                    Some(Ok(desc)) => match action {
+                        IndexAction::Delete => rsx! {
+                            p { "This permanently removes the index, its config and sources, {desc.num_published_splits} published splits holding {desc.num_published_docs} documents and {format_bytes(desc.size_published_splits)} of split files." }
+                        },
+                        IndexAction::Clear => rsx! {
+                            p { "This removes all {desc.num_published_splits} published splits holding {desc.num_published_docs} documents and {format_bytes(desc.size_published_splits)}. The index config and sources are kept." }
+                        },
+                        IndexAction::DeleteSplits => rsx! {
+                            p { "Selected splits are marked for deletion and removed by the janitor. The index holds {desc.num_published_docs} documents in {desc.num_published_splits} published splits." }
+                        },
+                    },
+                    Some(Err(e)) => rsx! {
+                        p { color: "#e57373", "{e}" }
+                    },
+                    None => rsx! {
+                        p { color: "#b0b0b0", "Loading..." }
+                    },
+                }
+
+                match action {
+                    IndexAction::Delete => rsx! {
+                        h3 { style: "font-size: 13px; font-weight: 600; margin: 12px 0 6px;", "Dry run" }
+                        match &*dry_run.read() {
+                            Some(Some(Ok(files))) => rsx! {
+                                p { color: "#b0b0b0",
+                                    "{files.len()} files, {format_bytes(files.iter().map(|file| file.file_size_bytes).sum())} would be deleted."
+                                }
+                                ul { max_height: "160px", overflow_y: "auto", font_family: "monospace", font_size: "12px", color: "#b0b0b0", margin_top: "6px",
+                                    for file in files {
+                                        li { key: "{file.file_name}", "{file.file_name} ({format_bytes(file.file_size_bytes)})" }
+                                    }
+                                }
+                            },
+                            Some(Some(Err(e))) => rsx! {
+                                p { color: "#e57373", "{e}" }
+                            },
+                            _ => rsx! {
+                                p { color: "#b0b0b0", "Loading..." }
+                            },
+                        }
+                    },
+                    IndexAction::Clear => rsx! {
+                        p { color: "#b0b0b0", margin_top: "8px", "Quickwit has no dry run for clearing an index; the summary above is what will be removed." }
+                    },
+                    IndexAction::DeleteSplits => rsx! {
+                        match splits().flatten() {
+                            Some(splits) => rsx! {
+                                SplitPicker { splits, selected }
+                            },
+                            None => rsx! {
+                                p { color: "#b0b0b0", "Loading..." }
+                            },
+                        }
+                        p { color: "#b0b0b0",
+                            "{selected_splits.len()} splits selected, {selected_splits.iter().map(|split| split.num_docs).sum::<u64>()} documents, {format_bytes(selected_splits.iter().map(|split| split.uncompressed_docs_size_in_bytes).sum())} uncompressed."
+                        }
+                    },
+                }
+
+                label { style: "display: block; margin: 16px 0 6px; color: #b0b0b0;",
+                    "Type "
+                    code { color: "#f8f9fa", "{index_id}" }
+                    " to confirm."
+                }
+                input {
+                    style: "width: 100%; background: #181a1b; border: 1px solid #404040; border-radius: 4px; color: #f8f9fa; padding: 6px 8px;",
+                    value: "{confirmation}",
+                    oninput: move |e| confirmation.set(e.value()),
+                }
+
+                if let Some(e) = error() {
+                    p { color: "#e57373", margin_top: "8px", "{e}" }
+                }
+
+                div { display: "flex", justify_content: "flex-end", gap: "8px", margin_top: "16px",
+                    button {
+                        r#type: "button",
+                        border: "1px solid #404040",
+                        border_radius: "4px",
+                        padding: "6px 16px",
+                        color: "#f8f9fa",
+                        background: "none",
+                        cursor: "pointer",
+                        onclick: move |_| on_close.call(()),
+                        "CANCEL"
+                    }
+                    button {
+                        r#type: "button",
+                        border: "0",
+                        border_radius: "4px",
+                        padding: "6px 16px",
+                        letter_spacing: "0.02857em",
+                        color: if can_run { "white" } else { "#4C4D4D" },
+                        background_color: if can_run { "#c62828" } else { "#151718" },
+                        cursor: if can_run { "pointer" } else { "not-allowed" },
+                        disabled: !can_run,
+                        onclick: move |_| {
+                            let index_id = target_index_id.clone();
+                            async move {
+                                running.set(true);
+                                error.set(None);
+                                let split_ids = selected.read().clone();
+                                match action.run(&index_id, &split_ids).await {
+                                    Ok(()) => {
+                                        metadata.invalidate();
+                                        on_done.call(());
+                                    }
+                                    Err(e) => error.set(Some(e.to_string())),
+                                }
+                                running.set(false);
+                            }
+                        },
+                        if running() {
+                            "RUNNING..."
+                        } else {
+                            "{action}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
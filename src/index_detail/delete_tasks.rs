@@ -0,0 +1,379 @@
+use crate::create_index::{FormRow, INPUT_STYLE, Section};
+use crate::indexes::format_utc;
+use crate::quickwit::QuickwitApi;
+use crate::quickwit::delete_task::{DeleteQueryRequest, DeleteTask};
+use crate::quickwit::index::{Split, SplitState};
+use chrono::{NaiveDateTime, TimeZone, Utc};
+use dioxus::prelude::*;
+
+fn parse_datetime_local(value: &str) -> Option<i64> {
+    NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M")
+        .ok()
+        .map(|datetime| Utc.from_utc_datetime(&datetime).timestamp())
+}
+
+fn format_time_range(start_timestamp: Option<i64>, end_timestamp: Option<i64>) -> String {
+    match (start_timestamp, end_timestamp) {
+        (None, None) => "all time".to_string(),
+        (start, end) => format!(
+            "{} → {}",
+            start.map(format_utc).unwrap_or_else(|| "…".to_string()),
+            end.map(format_utc).unwrap_or_else(|| "now".to_string()),
+        ),
+    }
+}
+
+/// Published splits whose delete opstamp has caught up with the task, out of
+/// all published splits. The janitor visits every split, matching or not.
+fn task_progress(task: &DeleteTask, splits: &[Split]) -> (usize, usize) {
+    let published = splits
+        .iter()
+        .filter(|split| split.split_state == SplitState::Published);
+    let total = published.clone().count();
+    let applied = published
+        .filter(|split| split.delete_opstamp >= task.opstamp)
+        .count();
+    (applied, total)
+}
+
+/// Previews how many documents a delete query matches, then creates the
+/// delete task once the index id has been typed in full.
+#[component]
+pub fn CreateDeleteTaskDialog(
+    index_id: String,
+    query: String,
+    start_timestamp: Option<i64>,
+    end_timestamp: Option<i64>,
+    on_close: EventHandler<()>,
+    on_created: EventHandler<()>,
+) -> Element {
+    let mut confirmation = use_signal(String::new);
+    let mut running = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    let mut created: Signal<Option<DeleteTask>> = use_signal(|| None);
+
+    let preview = use_resource({
+        let index_id = index_id.clone();
+        let query = query.clone();
+        move || {
+            let index_id = index_id.clone();
+            let query = query.clone();
+            async move {
+                let start = start_timestamp.map(|ts| ts.to_string()).unwrap_or_default();
+                let end = end_timestamp.map(|ts| ts.to_string()).unwrap_or_default();
+                QuickwitApi::query(&index_id)
+                    .query(&query)
+                    .max_hits(0)
+                    .time_range(&start, &end)
+                    .fetch()
+                    .await
+                    .map(|response| response.num_hits)
+            }
+        }
+    });
+
+    let previewed = matches!(&*preview.read(), Some(Ok(_)));
+    let can_run = previewed
+        && !query.trim().is_empty()
+        && confirmation() == index_id
+        && created().is_none()
+        && !running();
+    let target_index_id = index_id.clone();
+    let request = DeleteQueryRequest {
+        query: query.clone(),
+        start_timestamp,
+        end_timestamp,
+    };
+
+    rsx! {
+        div {
+            position: "fixed",
+            top: "0",
+            right: "0",
+            bottom: "0",
+            left: "0",
+            z_index: 1400,
+            background: "rgba(0, 0, 0, 0.6)",
+            display: "flex",
+            align_items: "center",
+            justify_content: "center",
+            cursor: "default",
+            onclick: |e| e.stop_propagation(),
+            div {
+                width: "640px",
+                max_height: "80vh",
+                overflow_y: "auto",
+                padding: "20px",
+                background: "#1e1e1e",
+                border: "1px solid #404040",
+                border_radius: "4px",
+                color: "#f8f9fa",
+                font_size: "13px",
+                font_weight: "400",
+                text_align: "left",
+                h2 { style: "font-size: 14px; font-weight: 700; margin-bottom: 12px;",
+                    "Delete documents: {index_id}"
+                }
+                table { style: "width: 100%; border-collapse: collapse; margin-bottom: 12px;",
+                    tbody {
+                        tr {
+                            td { style: "padding: 4px 8px 4px 0; color: #b0b0b0; width: 100px;", "Query" }
+                            td { style: "padding: 4px 0; font-family: monospace; word-break: break-all;", "{query}" }
+                        }
+                        tr {
+                            td { style: "padding: 4px 8px 4px 0; color: #b0b0b0;", "Time range" }
+                            td { style: "padding: 4px 0;", "{format_time_range(start_timestamp, end_timestamp)}" }
+                        }
+                    }
+                }
+
+                match &*preview.read() {
+                    Some(Ok(num_hits)) => rsx! {
+                        p { "{num_hits} documents currently match and will be deleted. Documents ingested later that match are not affected." }
+                    },
+                    Some(Err(e)) => rsx! {
+                        p { color: "#e57373", "Preview failed: {e}" }
+                    },
+                    None => rsx! {
+                        p { color: "#b0b0b0", "Counting matching documents..." }
+                    },
+                }
+
+                if let Some(task) = created() {
+                    p { color: "#81c784", margin_top: "12px",
+                        "Delete task created with opstamp {task.opstamp}. Splits are rewritten in the background; track progress in the index's delete tasks tab."
+                    }
+                } else {
+                    label { style: "display: block; margin: 16px 0 6px; color: #b0b0b0;",
+                        "Type "
+                        code { color: "#f8f9fa", "{index_id}" }
+                        " to confirm."
+                    }
+                    input {
+                        style: "width: 100%; background: #181a1b; border: 1px solid #404040; border-radius: 4px; color: #f8f9fa; padding: 6px 8px;",
+                        value: "{confirmation}",
+                        oninput: move |e| confirmation.set(e.value()),
+                    }
+                }
+
+                if let Some(e) = error() {
+                    p { color: "#e57373", margin_top: "8px", "{e}" }
+                }
+
+                div { display: "flex", justify_content: "flex-end", gap: "8px", margin_top: "16px",
+                    button {
+                        r#type: "button",
+                        border: "1px solid #404040",
+                        border_radius: "4px",
+                        padding: "6px 16px",
+                        color: "#f8f9fa",
+                        background: "none",
+                        cursor: "pointer",
+                        onclick: move |_| on_close.call(()),
+                        if created().is_some() {
+                            "CLOSE"
+                        } else {
+                            "CANCEL"
+                        }
+                    }
+                    if created().is_none() {
+                        button {
+                            r#type: "button",
+                            border: "0",
+                            border_radius: "4px",
+                            padding: "6px 16px",
+                            letter_spacing: "0.02857em",
+                            color: if can_run { "white" } else { "#4C4D4D" },
+                            background_color: if can_run { "#c62828" } else { "#151718" },
+                            cursor: if can_run { "pointer" } else { "not-allowed" },
+                            disabled: !can_run,
+                            onclick: move |_| {
+                                let index_id = target_index_id.clone();
+                                let request = request.clone();
+                                async move {
+                                    running.set(true);
+                                    error.set(None);
+                                    match QuickwitApi::create_delete_task(&index_id, &request).await {
+                                        Ok(task) => {
+                                            created.set(Some(task));
+                                            on_created.call(());
+                                        }
+                                        Err(e) => error.set(Some(e.to_string())),
+                                    }
+                                    running.set(false);
+                                }
+                            },
+                            if running() {
+                                "CREATING..."
+                            } else {
+                                "CREATE DELETE TASK"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn DeleteTaskRow(task: DeleteTask, splits: Vec<Split>) -> Element {
+    let (applied, total) = task_progress(&task, &splits);
+    let done = applied == total;
+    let status_color = if done { "#81c784" } else { "#ffb74d" };
+    let query = &task.delete_query;
+
+    rsx! {
+        tr { style: "border-bottom: 1px solid #2a2a2a;",
+            td { style: "padding: 8px 12px; color: #f8f9fa;", "{task.opstamp}" }
+            td { style: "padding: 8px 12px; color: #b0b0b0;", "{format_utc(task.create_timestamp)}" }
+            td { style: "padding: 8px 12px; color: #f8f9fa; font-family: monospace; font-size: 12px; word-break: break-all;",
+                "{query.query_ast}"
+            }
+            td { style: "padding: 8px 12px; color: #b0b0b0;",
+                "{format_time_range(query.start_timestamp, query.end_timestamp)}"
+            }
+            td { style: "padding: 8px 12px; width: 200px;",
+                div { height: "6px", background: "#2a2a2a", border_radius: "3px", overflow: "hidden",
+                    div {
+                        height: "100%",
+                        width: "{applied as f64 / total.max(1) as f64 * 100.0}%",
+                        background: if done { "#81c784" } else { "rgb(25, 118, 210)" },
+                    }
+                }
+                p { color: "#b0b0b0", font_size: "12px", margin_top: "4px",
+                    "{applied}/{total} splits"
+                }
+            }
+            td { style: "padding: 8px 12px; color: {status_color};",
+                if done {
+                    "Applied"
+                } else {
+                    "Pending"
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn DeleteTasksTab(index_id: String) -> Element {
+    let mut query = use_signal(String::new);
+    let mut start = use_signal(String::new);
+    let mut end = use_signal(String::new);
+    let mut previewing = use_signal(|| false);
+
+    let mut tasks = use_resource(use_reactive!(|index_id| async move {
+        let tasks = QuickwitApi::delete_tasks(&index_id).await?;
+        let splits = QuickwitApi::index_splits(&index_id).await?.splits;
+        anyhow::Ok((tasks, splits))
+    }));
+
+    let start_timestamp = parse_datetime_local(&start());
+    let end_timestamp = parse_datetime_local(&end());
+    let can_preview = !query().trim().is_empty();
+
+    rsx! {
+        div { padding: "12px 16px",
+            Section { title: "New delete task",
+                FormRow { label: "Query",
+                    input {
+                        style: "{INPUT_STYLE} width: 400px; font-family: monospace;",
+                        placeholder: "user_id:1234",
+                        value: "{query}",
+                        oninput: move |e| query.set(e.value()),
+                    }
+                }
+                FormRow { label: "Start (UTC)",
+                    input {
+                        r#type: "datetime-local",
+                        style: INPUT_STYLE,
+                        value: "{start}",
+                        oninput: move |e| start.set(e.value()),
+                    }
+                }
+                FormRow { label: "End (UTC)",
+                    input {
+                        r#type: "datetime-local",
+                        style: INPUT_STYLE,
+                        value: "{end}",
+                        oninput: move |e| end.set(e.value()),
+                    }
+                }
+                button {
+                    r#type: "button",
+                    border: "0",
+                    border_radius: "4px",
+                    padding: "6px 16px",
+                    letter_spacing: "0.02857em",
+                    color: if can_preview { "white" } else { "#4C4D4D" },
+                    background_color: if can_preview { "rgb(25, 118, 210)" } else { "#151718" },
+                    cursor: if can_preview { "pointer" } else { "not-allowed" },
+                    disabled: !can_preview,
+                    onclick: move |_| previewing.set(true),
+                    "PREVIEW"
+                }
+            }
+            if previewing() {
+                CreateDeleteTaskDialog {
+                    index_id: index_id.clone(),
+                    query: query(),
+                    start_timestamp,
+                    end_timestamp,
+                    on_close: move |_| previewing.set(false),
+                    on_created: move |_| tasks.restart(),
+                }
+            }
+
+            Section { title: "Delete tasks",
+                match &*tasks.read() {
+                    Some(Ok((tasks, _))) if tasks.is_empty() => rsx! {
+                        p { style: "color: #b0b0b0; font-size: 13px;", "No delete tasks." }
+                    },
+                    Some(Ok((tasks, splits))) => rsx! {
+                        table { style: "width: 100%; border-collapse: collapse; font-size: 13px;",
+                            thead {
+                                tr { style: "border-bottom: 1px solid #404040;",
+                                    for name in ["Opstamp", "Created", "Query", "Time range", "Progress", "Status"] {
+                                        th {
+                                            key: "{name}",
+                                            style: "padding: 8px 12px; text-align: left; color: #f8f9fa;",
+                                            "{name}"
+                                        }
+                                    }
+                                }
+                            }
+                            tbody {
+                                for task in tasks.iter().rev() {
+                                    DeleteTaskRow {
+                                        key: "{task.opstamp}",
+                                        task: task.clone(),
+                                        splits: splits.clone(),
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    Some(Err(e)) => rsx! {
+                        p { style: "color: #e57373; font-size: 13px;", "{e}" }
+                    },
+                    None => rsx! {
+                        p { style: "color: #b0b0b0; font-size: 13px;", "Loading..." }
+                    },
+                }
+                button {
+                    r#type: "button",
+                    margin_top: "8px",
+                    border: "1px solid #404040",
+                    border_radius: "4px",
+                    padding: "6px 16px",
+                    color: "#f8f9fa",
+                    background: "none",
+                    cursor: "pointer",
+                    onclick: move |_| tasks.restart(),
+                    "REFRESH"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,291 @@
+use crate::create_index::{FieldRow, RetentionSection, SearchSettingsSection, Section};
+use crate::quickwit::QuickwitApi;
+use crate::quickwit::index::{FieldMapping, Index, IndexConfig};
+use dioxus::prelude::*;
+use serde_json::Value;
+use std::collections::BTreeSet;
+
+#[derive(Debug, Clone, PartialEq)]
+struct ConfigChange {
+    /// JSON pointer into the serialized config.
+    pointer: String,
+    before: Option<Value>,
+    after: Option<Value>,
+}
+
+impl ConfigChange {
+    fn path(&self) -> String {
+        self.pointer.trim_start_matches('/').replace('/', ".")
+    }
+
+    /// Quickwit updates search settings and retention in place. From 0.9 on
+    /// it also updates indexing settings and accepts new doc mapping fields;
+    /// anything else is refused.
+    fn is_updatable(&self, supports_full_update: bool) -> bool {
+        let pointer = self.pointer.as_str();
+        pointer.starts_with("/search_settings")
+            || pointer.starts_with("/retention")
+            || (supports_full_update
+                && (pointer.starts_with("/indexing_settings")
+                    || (pointer.starts_with("/doc_mapping/field_mappings/")
+                        && pointer.matches('/').count() == 3
+                        && self.before.is_none())))
+    }
+}
+
+fn diff(
+    pointer: &str,
+    before: Option<&Value>,
+    after: Option<&Value>,
+    changes: &mut Vec<ConfigChange>,
+) {
+    match (before, after) {
+        (Some(Value::Object(before)), Some(Value::Object(after))) => {
+            let keys: BTreeSet<&String> = before.keys().chain(after.keys()).collect();
+            for key in keys {
+                diff(
+                    &format!("{pointer}/{key}"),
+                    before.get(key),
+                    after.get(key),
+                    changes,
+                );
+            }
+        }
+        // Lists of objects (field mappings) are compared item by item, lists
+        // of scalars as a whole.
+        (Some(Value::Array(before)), Some(Value::Array(after)))
+            if before.iter().chain(after).any(Value::is_object) =>
+        {
+            for i in 0..before.len().max(after.len()) {
+                diff(
+                    &format!("{pointer}/{i}"),
+                    before.get(i),
+                    after.get(i),
+                    changes,
+                );
+            }
+        }
+        _ if before != after => changes.push(ConfigChange {
+            pointer: pointer.to_string(),
+            before: before.cloned(),
+            after: after.cloned(),
+        }),
+        _ => {}
+    }
+}
+
+fn config_changes(current: &IndexConfig, updated: &IndexConfig) -> Vec<ConfigChange> {
+    let mut changes = Vec::new();
+    let current = serde_json::to_value(current).unwrap_or_default();
+    let updated = serde_json::to_value(updated).unwrap_or_default();
+    diff("", Some(&current), Some(&updated), &mut changes);
+    changes
+}
+
+/// Whether `saved` holds everything in `expected`; Quickwit fills in defaults
+/// for new fields, so objects only need to contain the requested keys.
+fn is_applied(saved: Option<&Value>, expected: Option<&Value>) -> bool {
+    match (saved, expected) {
+        (Some(Value::Object(saved)), Some(Value::Object(expected))) => expected
+            .iter()
+            .all(|(key, value)| is_applied(saved.get(key), Some(value))),
+        (saved, expected) => saved == expected,
+    }
+}
+
+fn format_value(value: &Option<Value>) -> String {
+    match value {
+        Some(value) => value.to_string(),
+        None => "—".to_string(),
+    }
+}
+
+#[component]
+pub fn EditConfig(index: Index, mut editing: Signal<bool>, on_saved: EventHandler<()>) -> Element {
+    // The config as it was when editing started, kept after saving so the
+    // outcome of each change stays visible.
+    let original = use_hook(|| index.index_config.clone());
+    let num_existing_fields = original.doc_mapping.field_mappings.len();
+    let mut config = use_signal(|| original.clone());
+    let mut saving = use_signal(|| false);
+    // The config returned by Quickwit, or the error it answered with.
+    let mut outcome: Signal<Option<Result<Value, String>>> = use_signal(|| None);
+
+    let version = use_resource(QuickwitApi::version);
+    // Assume the newer behaviour until the version is known.
+    let supports_full_update = version
+        .read()
+        .as_ref()
+        .and_then(|version| version.as_ref().ok())
+        .is_none_or(|version| version.build.is_at_least(0, 9));
+
+    let current = config.read().clone();
+    let changes = config_changes(&original, &current);
+    let errors = current.validate();
+    let can_save = !changes.is_empty() && errors.is_empty() && !saving();
+
+    let status = |change: &ConfigChange| -> (&'static str, &'static str) {
+        match outcome.read().as_ref() {
+            Some(Ok(saved))
+                if is_applied(saved.pointer(&change.pointer), change.after.as_ref()) =>
+            {
+                ("Applied", "#81c784")
+            }
+            Some(Ok(_)) => ("Not applied", "#ffb74d"),
+            Some(Err(_)) => ("Rejected", "#e57373"),
+            None if change.is_updatable(supports_full_update) => ("Updatable", "#81c784"),
+            None => ("Will be rejected", "#e57373"),
+        }
+    };
+
+    rsx! {
+        div { padding: "12px 16px",
+            Section { title: "Doc mapping",
+                div { style: "background: #181a1b; border: 1px solid #404040; border-radius: 4px; margin: 8px 0;",
+                    for field in current.doc_mapping.field_mappings.iter().take(num_existing_fields) {
+                        div {
+                            key: "{field.name}",
+                            padding: "8px",
+                            border_bottom: "1px solid #2a2a2a",
+                            color: "#b0b0b0",
+                            font_size: "13px",
+                            "{field.name}: {field.r#type}"
+                        }
+                    }
+                    for index in num_existing_fields..current.doc_mapping.field_mappings.len() {
+                        FieldRow { key: "{index}", config, index }
+                    }
+                    button {
+                        r#type: "button",
+                        margin: "8px",
+                        border: "0",
+                        border_radius: "4px",
+                        padding: "6px 16px",
+                        color: "white",
+                        background_color: "black",
+                        cursor: "pointer",
+                        onclick: move |_| {
+                            config
+                                .write()
+                                .doc_mapping
+                                .field_mappings
+                                .push(FieldMapping {
+                                    r#type: "text".to_string(),
+                                    ..Default::default()
+                                });
+                        },
+                        "ADD FIELD"
+                    }
+                }
+            }
+            SearchSettingsSection { config }
+            RetentionSection { config }
+
+            Section { title: "Changes",
+                if changes.is_empty() {
+                    p { style: "color: #b0b0b0; font-size: 13px;", "No changes." }
+                } else {
+                    table { style: "width: 100%; border-collapse: collapse; font-size: 13px;",
+                        thead {
+                            tr { style: "border-bottom: 1px solid #404040;",
+                                for name in ["Setting", "Current", "New", "Status"] {
+                                    th {
+                                        key: "{name}",
+                                        style: "padding: 8px 12px; text-align: left; color: #f8f9fa;",
+                                        "{name}"
+                                    }
+                                }
+                            }
+                        }
+                        tbody {
+                            for change in changes.iter() {
+                                tr {
+                                    key: "{change.pointer}",
+                                    style: "border-bottom: 1px solid #2a2a2a;",
+                                    td { style: "padding: 8px 12px; color: #f8f9fa; font-family: monospace;",
+                                        "{change.path()}"
+                                    }
+                                    td { style: "padding: 8px 12px; color: #b0b0b0; word-break: break-all;",
+                                        "{format_value(&change.before)}"
+                                    }
+                                    td { style: "padding: 8px 12px; color: #f8f9fa; word-break: break-all;",
+                                        "{format_value(&change.after)}"
+                                    }
+                                    td { style: "padding: 8px 12px; color: {status(change).1};",
+                                        "{status(change).0}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if !errors.is_empty() {
+                ul { style: "color: #e57373; font-size: 13px; margin-bottom: 12px; padding-left: 16px;",
+                    for error in errors.iter() {
+                        li { key: "{error}", "{error}" }
+                    }
+                }
+            }
+            if let Some(Err(error)) = outcome() {
+                p { style: "color: #e57373; font-size: 13px; margin-bottom: 12px;", "{error}" }
+            }
+
+            div { display: "flex", gap: "8px",
+                button {
+                    r#type: "button",
+                    border: "0",
+                    border_radius: "4px",
+                    padding: "6px 16px",
+                    letter_spacing: "0.02857em",
+                    color: if can_save { "white" } else { "#4C4D4D" },
+                    background_color: if can_save { "rgb(25, 118, 210)" } else { "#151718" },
+                    cursor: if can_save { "pointer" } else { "not-allowed" },
+                    disabled: !can_save,
+                    onclick: move |_| {
+                        let index_id = index.index_config.index_id.clone();
+                        async move {
+                            saving.set(true);
+                            let request = config.read().clone();
+                            match QuickwitApi::update_index(&index_id, &request).await {
+                                Ok(saved) => {
+                                    outcome
+                                        .set(
+                                            Some(
+                                                Ok(serde_json::to_value(&saved.index_config).unwrap_or_default()),
+                                            ),
+                                        );
+                                    on_saved.call(());
+                                }
+                                Err(e) => outcome.set(Some(Err(e.to_string()))),
+                            }
+                            saving.set(false);
+                        }
+                    },
+                    if saving() {
+                        "SAVING..."
+                    } else {
+                        "SAVE"
+                    }
+                }
+                button {
+                    r#type: "button",
+                    border: "1px solid #404040",
+                    border_radius: "4px",
+                    padding: "6px 16px",
+                    letter_spacing: "0.02857em",
+                    color: "#f8f9fa",
+                    background: "none",
+                    cursor: "pointer",
+                    onclick: move |_| editing.set(false),
+                    if matches!(outcome(), Some(Ok(_))) {
+                        "DONE"
+                    } else {
+                        "CANCEL"
+                    }
+                }
+            }
+        }
+    }
+}
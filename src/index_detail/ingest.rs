@@ -0,0 +1,268 @@
+use crate::create_index::{Checkbox, INPUT_STYLE};
+use crate::quickwit::QuickwitApi;
+use crate::quickwit::index::{DocMapping, Index};
+use crate::quickwit::ingest::{CommitMode, validate_document};
+use dioxus::prelude::*;
+
+// Kept well under Quickwit's 10MB request limit.
+const BATCH_MAX_DOCS: usize = 1000;
+const BATCH_MAX_BYTES: usize = 5 * 1024 * 1024;
+const MAX_SHOWN_ERRORS: usize = 100;
+
+#[derive(Debug, Clone, PartialEq)]
+struct LineError {
+    line: usize,
+    errors: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+struct Validation {
+    valid_lines: Vec<String>,
+    invalid_lines: Vec<LineError>,
+}
+
+fn validate_ndjson(doc_mapping: &DocMapping, text: &str) -> Validation {
+    let mut validation = Validation::default();
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let errors = match serde_json::from_str::<serde_json::Value>(line) {
+            Ok(document) => validate_document(doc_mapping, &document),
+            Err(e) => vec![format!("invalid JSON: {e}")],
+        };
+        if errors.is_empty() {
+            validation.valid_lines.push(line.to_string());
+        } else {
+            validation.invalid_lines.push(LineError {
+                line: i + 1,
+                errors,
+            });
+        }
+    }
+    validation
+}
+
+fn batches(lines: &[String]) -> Vec<String> {
+    let mut batches = Vec::new();
+    let mut batch = String::new();
+    let mut num_docs = 0;
+    for line in lines {
+        if num_docs == BATCH_MAX_DOCS
+            || (num_docs > 0 && batch.len() + line.len() + 1 > BATCH_MAX_BYTES)
+        {
+            batches.push(std::mem::take(&mut batch));
+            num_docs = 0;
+        }
+        batch.push_str(line);
+        batch.push('\n');
+        num_docs += 1;
+    }
+    if num_docs > 0 {
+        batches.push(batch);
+    }
+    batches
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+struct Progress {
+    sent_batches: usize,
+    total_batches: usize,
+    docs_for_processing: u64,
+    rejected_docs: u64,
+}
+
+#[component]
+pub fn IngestTab(index: Index) -> Element {
+    let index_id = index.index_config.index_id.clone();
+    let doc_mapping = index.index_config.doc_mapping.clone();
+
+    let mut text = use_signal(String::new);
+    let mut commit_mode = use_signal(CommitMode::default);
+    let mut skip_invalid = use_signal(|| false);
+    let mut running = use_signal(|| false);
+    let mut progress: Signal<Option<Progress>> = use_signal(|| None);
+    let mut ingest_error: Signal<Option<String>> = use_signal(|| None);
+
+    let validation = use_memo(move || validate_ndjson(&doc_mapping, &text.read()));
+
+    let Validation {
+        valid_lines,
+        invalid_lines,
+    } = validation();
+    let can_ingest =
+        !valid_lines.is_empty() && (invalid_lines.is_empty() || skip_invalid()) && !running();
+
+    rsx! {
+        div { padding: "12px 16px", display: "flex", flex_direction: "column", gap: "12px",
+            div { display: "flex", align_items: "center", gap: "12px",
+                input {
+                    r#type: "file",
+                    accept: ".ndjson,.jsonl,.json,.txt",
+                    color: "#b0b0b0",
+                    font_size: "13px",
+                    onchange: move |evt: FormEvent| async move {
+                        let Some(file_engine) = evt.files() else {
+                            return;
+                        };
+                        for name in file_engine.files() {
+                            if let Some(contents) = file_engine.read_file_to_string(&name).await {
+                                text.set(contents);
+                            }
+                        }
+                    },
+                }
+                span { color: "#5F6060", font_size: "13px", "or paste one JSON document per line" }
+            }
+            textarea {
+                style: "{INPUT_STYLE} width: 100%; height: 240px; font-family: monospace; resize: vertical;",
+                placeholder: "{{\"timestamp\": \"2024-01-01T00:00:00Z\", \"message\": \"hello\"}}",
+                value: "{text}",
+                oninput: move |e| text.set(e.value()),
+            }
+
+            p { color: "#b0b0b0", font_size: "13px",
+                "{valid_lines.len()} valid, {invalid_lines.len()} invalid lines"
+            }
+            if !invalid_lines.is_empty() {
+                ul { style: "max-height: 200px; overflow-y: auto; color: #e57373; font-size: 12px; font-family: monospace; padding-left: 16px;",
+                    for line_error in invalid_lines.iter().take(MAX_SHOWN_ERRORS) {
+                        li { key: "{line_error.line}",
+                            "line {line_error.line}: {line_error.errors.join(\"; \")}"
+                        }
+                    }
+                    if invalid_lines.len() > MAX_SHOWN_ERRORS {
+                        li { "... and {invalid_lines.len() - MAX_SHOWN_ERRORS} more" }
+                    }
+                }
+            }
+
+            div { display: "flex", align_items: "center", gap: "16px",
+                label { color: "#b0b0b0", font_size: "13px", "Commit" }
+                select {
+                    style: INPUT_STYLE,
+                    onchange: move |e| {
+                        if let Some(mode) = CommitMode::ALL
+                            .into_iter()
+                            .find(|mode| mode.as_str() == e.value())
+                        {
+                            commit_mode.set(mode);
+                        }
+                    },
+                    for mode in CommitMode::ALL {
+                        option {
+                            key: "{mode}",
+                            value: "{mode}",
+                            selected: mode == commit_mode(),
+                            "{mode}"
+                        }
+                    }
+                }
+                Checkbox {
+                    label: "skip invalid lines",
+                    checked: skip_invalid(),
+                    onchange: move |checked| skip_invalid.set(checked),
+                }
+                button {
+                    r#type: "button",
+                    border: "0",
+                    border_radius: "4px",
+                    padding: "6px 16px",
+                    letter_spacing: "0.02857em",
+                    color: if can_ingest { "white" } else { "#4C4D4D" },
+                    background_color: if can_ingest { "rgb(25, 118, 210)" } else { "#151718" },
+                    cursor: if can_ingest { "pointer" } else { "not-allowed" },
+                    disabled: !can_ingest,
+                    onclick: move |_| {
+                        let index_id = index_id.clone();
+                        let pending = batches(&validation.read().valid_lines);
+                        async move {
+                            running.set(true);
+                            ingest_error.set(None);
+                            let mut current = Progress {
+                                total_batches: pending.len(),
+                                ..Default::default()
+                            };
+                            progress.set(Some(current));
+                            for (i, batch) in pending.into_iter().enumerate() {
+                                match QuickwitApi::ingest(&index_id, batch, commit_mode()).await {
+                                    Ok(response) => {
+                                        current.sent_batches += 1;
+                                        current.docs_for_processing += response.num_docs_for_processing;
+                                        current.rejected_docs += response.num_rejected_docs.unwrap_or_default();
+                                        progress.set(Some(current));
+                                    }
+                                    Err(e) => {
+                                        ingest_error.set(Some(format!("batch {}: {e}", i + 1)));
+                                        break;
+                                    }
+                                }
+                            }
+                            running.set(false);
+                        }
+                    },
+                    if running() {
+                        "INGESTING..."
+                    } else {
+                        "INGEST"
+                    }
+                }
+            }
+
+            if let Some(progress) = progress() {
+                div {
+                    div { height: "6px", background: "#2a2a2a", border_radius: "3px", overflow: "hidden",
+                        div {
+                            height: "100%",
+                            width: "{progress.sent_batches as f64 / progress.total_batches.max(1) as f64 * 100.0}%",
+                            background: "rgb(25, 118, 210)",
+                        }
+                    }
+                    p { color: "#b0b0b0", font_size: "13px", margin_top: "6px",
+                        "{progress.sent_batches}/{progress.total_batches} batches sent, {progress.docs_for_processing} documents accepted for processing"
+                        if progress.rejected_docs > 0 {
+                            ", {progress.rejected_docs} rejected"
+                        }
+                    }
+                }
+            }
+            if let Some(e) = ingest_error() {
+                p { color: "#e57373", font_size: "13px", "{e}" }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn batches_split_on_doc_count() {
+        let lines = vec!["{}".to_string(); BATCH_MAX_DOCS * 2 + 1];
+        let batches = batches(&lines);
+        assert_eq!(batches.len(), 3);
+        assert_eq!(batches[0].lines().count(), BATCH_MAX_DOCS);
+        assert_eq!(batches[2], "{}\n");
+    }
+
+    #[test]
+    fn batches_split_on_byte_size() {
+        // With its newline each line takes exactly a quarter of the limit.
+        let lines = vec!["x".repeat(BATCH_MAX_BYTES / 4 - 1); 5];
+        let batches = batches(&lines);
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].len(), BATCH_MAX_BYTES);
+    }
+
+    #[test]
+    fn batches_keep_oversized_documents_on_their_own() {
+        let line = "x".repeat(BATCH_MAX_BYTES + 1);
+        assert_eq!(
+            batches(&["{}".to_string(), line, "{}".to_string()]).len(),
+            3
+        );
+        assert!(batches(&[]).is_empty());
+    }
+}
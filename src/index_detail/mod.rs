@@ -0,0 +1,298 @@
+mod delete_tasks;
+mod edit;
+mod ingest;
+mod sources;
+mod split_stats;
+mod splits;
+
+use crate::connectivity::use_reconnect;
+use crate::indexes::{format_bytes, format_utc};
+use crate::metadata::use_metadata_cache;
+use crate::quickwit::QuickwitApi;
+use crate::quickwit::index::Index;
+use crate::{Route, icons};
+use delete_tasks::DeleteTasksTab;
+use dioxus::prelude::*;
+use edit::EditConfig;
+use ingest::IngestTab;
+use sources::SourcesTab;
+use split_stats::SplitStatsTab;
+use splits::SplitsTab;
+use std::fmt;
+
+pub(crate) use delete_tasks::CreateDeleteTaskDialog;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum IndexTab {
+    Summary,
+    DocMapping,
+    Sources,
+    Splits,
+    SplitStats,
+    Settings,
+    Ingest,
+    DeleteTasks,
+}
+
+impl fmt::Display for IndexTab {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IndexTab::Summary => write!(f, "Summary"),
+            IndexTab::DocMapping => write!(f, "Doc mapping"),
+            IndexTab::Sources => write!(f, "Sources"),
+            IndexTab::Splits => write!(f, "Splits"),
+            IndexTab::SplitStats => write!(f, "Split stats"),
+            IndexTab::Settings => write!(f, "Settings"),
+            IndexTab::Ingest => write!(f, "Ingest"),
+            IndexTab::DeleteTasks => write!(f, "Delete tasks"),
+        }
+    }
+}
+
+impl IndexTab {
+    const ALL: [IndexTab; 8] = [
+        IndexTab::Summary,
+        IndexTab::DocMapping,
+        IndexTab::Sources,
+        IndexTab::Splits,
+        IndexTab::SplitStats,
+        IndexTab::Settings,
+        IndexTab::Ingest,
+        IndexTab::DeleteTasks,
+    ];
+}
+
+#[component]
+fn DetailRow(label: &'static str, children: Element) -> Element {
+    rsx! {
+        tr { style: "border-bottom: 1px solid #2a2a2a;",
+            td { style: "padding: 8px 12px; color: #b0b0b0; width: 280px;", "{label}" }
+            td { style: "padding: 8px 12px; color: #f8f9fa; word-break: break-all;", {children} }
+        }
+    }
+}
+
+#[component]
+fn SummaryTab(index: Index) -> Element {
+    let index_id = index.index_config.index_id.clone();
+    let data = use_resource(use_reactive!(|index_id| async move {
+        QuickwitApi::index_describe(&index_id)
+            .await
+            .map_err(|e| e.to_string())
+    }));
+
+    rsx! {
+        table { style: "width: 100%; border-collapse: collapse; font-size: 13px;",
+            tbody {
+                DetailRow { label: "Index ID", "{index.index_config.index_id}" }
+                DetailRow { label: "URI", "{index.index_config.index_uri}" }
+                DetailRow { label: "Created at", "{format_utc(index.create_timestamp)}" }
+                DetailRow { label: "Sources", "{index.sources.len()}" }
+                match data() {
+                    Some(Ok(desc)) => rsx! {
+                        DetailRow { label: "Number of published documents", "{desc.num_published_docs}" }
+                        DetailRow { label: "Size of published documents (uncompressed)",
+                            "{format_bytes(desc.size_published_docs_uncompressed)}"
+                        }
+                        DetailRow { label: "Number of published splits", "{desc.num_published_splits}" }
+                        DetailRow { label: "Size of published splits", "{format_bytes(desc.size_published_splits)}" }
+                        if let (Some(min), Some(max)) = (desc.min_timestamp, desc.max_timestamp) {
+                            DetailRow { label: "Timestamp range", "{format_utc(min)} - {format_utc(max)}" }
+                        }
+                    },
+                    Some(Err(e)) => rsx! {
+                        DetailRow { label: "Statistics",
+                            span { color: "#e57373", "{e}" }
+                        }
+                    },
+                    None => rsx! {
+                        DetailRow { label: "Statistics", "Loading..." }
+                    },
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn DocMappingTab(index: Index) -> Element {
+    let doc_mapping = index.index_config.doc_mapping;
+
+    rsx! {
+        p { style: "padding: 8px 12px; color: #b0b0b0; font-size: 13px;",
+            "Timestamp field: "
+            span { style: "color: #f8f9fa;", "{doc_mapping.timestamp_field}" }
+        }
+        table { style: "width: 100%; border-collapse: collapse; font-size: 13px;",
+            thead {
+                tr { style: "border-bottom: 1px solid #404040;",
+                    th { style: "padding: 8px 12px; text-align: left; color: #f8f9fa;", "Field" }
+                    th { style: "padding: 8px 12px; text-align: left; color: #f8f9fa;", "Type" }
+                }
+            }
+            tbody {
+                for field in doc_mapping.field_mappings {
+                    tr { key: "{field.name}", style: "border-bottom: 1px solid #2a2a2a;",
+                        td { style: "padding: 8px 12px; color: #f8f9fa;", "{field.name}" }
+                        td { style: "padding: 8px 12px; color: #b0b0b0;", "{field.r#type}" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn SettingsTab(index: Index) -> Element {
+    let config = index.index_config;
+    let json = |value: &Option<serde_json::Value>| {
+        value
+            .as_ref()
+            .map(|value| serde_json::to_string_pretty(value).unwrap_or_default())
+            .unwrap_or_else(|| "default".to_string())
+    };
+
+    rsx! {
+        table { style: "width: 100%; border-collapse: collapse; font-size: 13px;",
+            tbody {
+                DetailRow { label: "Commit timeout",
+                    match config.indexing_settings.commit_timeout_secs {
+                        Some(secs) => format!("{secs}s"),
+                        None => "default".to_string(),
+                    }
+                }
+                DetailRow { label: "Split target (docs)",
+                    match config.indexing_settings.split_num_docs_target {
+                        Some(docs) => docs.to_string(),
+                        None => "default".to_string(),
+                    }
+                }
+                DetailRow { label: "Merge policy",
+                    pre { "{json(&config.indexing_settings.merge_policy)}" }
+                }
+                DetailRow { label: "Resources",
+                    pre { "{json(&config.indexing_settings.resources)}" }
+                }
+                DetailRow { label: "Default search fields",
+                    "{config.search_settings.default_search_fields.join(\", \")}"
+                }
+                DetailRow { label: "Retention",
+                    match config.retention {
+                        Some(retention) => format!("{} (applied {})", retention.period, retention.schedule),
+                        None => "none".to_string(),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn IndexDetail(index_id: String) -> Element {
+    let mut tab = use_signal(|| IndexTab::Summary);
+    let mut editing = use_signal(|| false);
+
+    // The route reuses this component across indexes, so the id is tracked.
+    let mut index = use_resource(use_reactive!(|index_id| async move {
+        QuickwitApi::index_detail(&index_id).await
+    }));
+
+    let metadata = use_metadata_cache();
+
+    // Edits and source changes alter the cached metadata too.
+    let reload = move |_: ()| {
+        index.restart();
+        metadata.invalidate();
+    };
+    use_reconnect(move |_| index.restart());
+
+    rsx! {
+        div { height: "100%", padding: "20px", overflow_y: "auto",
+            div { display: "flex", align_items: "center", gap: "8px", margin_bottom: "16px",
+                Link { to: Route::Indexes {}, style: "color: #5F6060; font-size: 14px;", "Indexes" }
+                span { color: "#5F6060", icons::ChevronRight {} }
+                h1 { style: "font-size: 14px; font-weight: 700; color: #f8f9fa;", "{index_id}" }
+                Link {
+                    to: format!("/search?index={}", urlencoding::encode(&index_id)),
+                    style: "margin-left: auto; color: #f8f9fa; font-size: 13px; text-decoration: underline; text-decoration-color: #5F6060;",
+                    "Search this index"
+                }
+                button {
+                    r#type: "button",
+                    border: "0",
+                    border_radius: "4px",
+                    padding: "6px 16px",
+                    letter_spacing: "0.02857em",
+                    color: "white",
+                    background_color: if editing() { "#151718" } else { "black" },
+                    cursor: "pointer",
+                    onclick: move |_| editing.toggle(),
+                    "EDIT CONFIG"
+                }
+            }
+            div {
+                display: "flex",
+                gap: "4px",
+                border_bottom: "1px solid #404040",
+                margin_bottom: "12px",
+                for t in IndexTab::ALL {
+                    button {
+                        key: "{t}",
+                        r#type: "button",
+                        padding: "8px 16px",
+                        border: "none",
+                        border_bottom: if tab() == t { "2px solid rgb(25, 118, 210)" } else { "2px solid transparent" },
+                        background: "none",
+                        color: if tab() == t { "#f8f9fa" } else { "#5F6060" },
+                        cursor: "pointer",
+                        onclick: move |_| tab.set(t),
+                        "{t}"
+                    }
+                }
+            }
+            div { style: "background: #1e1e1e; box-shadow: 0 1px 3px rgba(0,0,0,0.3);",
+                match index.read().as_ref() {
+                    Some(Ok(current)) if editing() => rsx! {
+                        EditConfig {
+                            index: current.clone(),
+                            editing,
+                            on_saved: reload,
+                        }
+                    },
+                    Some(Ok(index)) => match tab() {
+                        IndexTab::Summary => rsx! {
+                            SummaryTab { index: index.clone() }
+                        },
+                        IndexTab::DocMapping => rsx! {
+                            DocMappingTab { index: index.clone() }
+                        },
+                        IndexTab::Sources => rsx! {
+                            SourcesTab { index: index.clone(), on_change: reload }
+                        },
+                        IndexTab::Splits => rsx! {
+                            SplitsTab { index_id: index.index_config.index_id.clone() }
+                        },
+                        IndexTab::SplitStats => rsx! {
+                            SplitStatsTab { index_id: index.index_config.index_id.clone() }
+                        },
+                        IndexTab::Settings => rsx! {
+                            SettingsTab { index: index.clone() }
+                        },
+                        IndexTab::Ingest => rsx! {
+                            IngestTab { index: index.clone() }
+                        },
+                        IndexTab::DeleteTasks => rsx! {
+                            DeleteTasksTab { index_id: index.index_config.index_id.clone() }
+                        },
+                    },
+                    Some(Err(e)) => rsx! {
+                        p { style: "padding: 12px 16px; color: #ff6b6b;", "{e}" }
+                    },
+                    None => rsx! {
+                        p { style: "padding: 12px 16px; color: #b0b0b0;", "Loading..." }
+                    },
+                }
+            }
+        }
+    }
+}
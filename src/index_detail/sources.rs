@@ -0,0 +1,424 @@
+use crate::create_index::{FormRow, INPUT_STYLE, Section};
+use crate::quickwit::QuickwitApi;
+use crate::quickwit::index::{Index, SOURCE_CONFIG_VERSION, Source};
+use dioxus::prelude::*;
+use serde_json::json;
+use std::fmt;
+
+const INPUT_FORMATS: [&str; 2] = ["json", "plain_text"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+enum SourceKind {
+    #[default]
+    Kafka,
+    Kinesis,
+    Pulsar,
+    File,
+    Sqs,
+    Ingest,
+}
+
+impl fmt::Display for SourceKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SourceKind::Kafka => write!(f, "Kafka"),
+            SourceKind::Kinesis => write!(f, "Kinesis"),
+            SourceKind::Pulsar => write!(f, "Pulsar"),
+            SourceKind::File => write!(f, "File"),
+            SourceKind::Sqs => write!(f, "SQS (S3 notifications)"),
+            SourceKind::Ingest => write!(f, "Ingest API"),
+        }
+    }
+}
+
+impl SourceKind {
+    const ALL: [SourceKind; 6] = [
+        SourceKind::Kafka,
+        SourceKind::Kinesis,
+        SourceKind::Pulsar,
+        SourceKind::File,
+        SourceKind::Sqs,
+        SourceKind::Ingest,
+    ];
+
+    fn source_type(&self) -> &'static str {
+        match self {
+            SourceKind::Kafka => "kafka",
+            SourceKind::Kinesis => "kinesis",
+            SourceKind::Pulsar => "pulsar",
+            // SQS-driven sources are file sources fed by notifications.
+            SourceKind::File | SourceKind::Sqs => "file",
+            SourceKind::Ingest => "ingest",
+        }
+    }
+
+    /// Labels of the two type-specific parameters, the second one optional.
+    fn param_labels(&self) -> (&'static str, Option<&'static str>) {
+        match self {
+            SourceKind::Kafka => ("Topic", Some("Bootstrap servers")),
+            SourceKind::Kinesis => ("Stream name", Some("Region")),
+            SourceKind::Pulsar => ("Topics (comma separated)", Some("Address")),
+            SourceKind::File => ("File path", None),
+            SourceKind::Sqs => ("Queue URL", None),
+            SourceKind::Ingest => ("", None),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct SourceForm {
+    kind: SourceKind,
+    source_id: String,
+    num_pipelines: u32,
+    input_format: String,
+    first_param: String,
+    second_param: String,
+}
+
+impl Default for SourceForm {
+    fn default() -> Self {
+        SourceForm {
+            kind: SourceKind::default(),
+            source_id: String::new(),
+            num_pipelines: 1,
+            input_format: "json".to_string(),
+            first_param: String::new(),
+            second_param: String::new(),
+        }
+    }
+}
+
+impl SourceForm {
+    fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        let source_id = &self.source_id;
+        if source_id.is_empty()
+            || source_id.starts_with('_')
+            || !source_id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_'))
+        {
+            errors.push(
+                "Source id must contain only letters, digits, '-' or '_' and not start with '_'"
+                    .to_string(),
+            );
+        }
+        if self.num_pipelines == 0 {
+            errors.push("At least one pipeline is required".to_string());
+        }
+        let (first_label, _) = self.kind.param_labels();
+        if !first_label.is_empty() && self.first_param.trim().is_empty() {
+            errors.push(format!("{first_label} is required"));
+        }
+        errors
+    }
+
+    fn params(&self) -> serde_json::Value {
+        let first = self.first_param.trim();
+        let second = self.second_param.trim();
+        let mut params = match self.kind {
+            SourceKind::Kafka => json!({ "topic": first, "client_params": {} }),
+            SourceKind::Kinesis => json!({ "stream_name": first }),
+            SourceKind::Pulsar => json!({
+                "topics": first.split(',').map(str::trim).collect::<Vec<_>>(),
+                "address": second,
+            }),
+            SourceKind::File => json!({ "filepath": first }),
+            SourceKind::Sqs => json!({
+                "notifications": [{
+                    "type": "sqs",
+                    "queue_url": first,
+                    "message_type": "s3_notification",
+                }],
+            }),
+            SourceKind::Ingest => json!({}),
+        };
+        if !second.is_empty() {
+            match self.kind {
+                SourceKind::Kafka => params["client_params"]["bootstrap.servers"] = json!(second),
+                SourceKind::Kinesis => params["region"] = json!(second),
+                _ => {}
+            }
+        }
+        params
+    }
+
+    fn to_source(&self) -> Source {
+        Source {
+            version: SOURCE_CONFIG_VERSION.to_string(),
+            source_id: self.source_id.clone(),
+            num_pipelines: self.num_pipelines,
+            enabled: true,
+            source_type: self.kind.source_type().to_string(),
+            input_format: self.input_format.clone(),
+            params: self.params(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SourceAction {
+    ResetCheckpoint,
+    Delete,
+}
+
+#[component]
+fn SourceRow(index_id: String, source: Source, on_change: EventHandler<()>) -> Element {
+    let mut confirming: Signal<Option<SourceAction>> = use_signal(|| None);
+    let mut busy = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let run = use_callback({
+        let index_id = index_id.clone();
+        let source_id = source.source_id.clone();
+        let enabled = source.enabled;
+        move |action: Option<SourceAction>| {
+            let index_id = index_id.clone();
+            let source_id = source_id.clone();
+            spawn(async move {
+                busy.set(true);
+                error.set(None);
+                let result = match action {
+                    None => QuickwitApi::toggle_source(&index_id, &source_id, !enabled).await,
+                    Some(SourceAction::ResetCheckpoint) => {
+                        QuickwitApi::reset_source_checkpoint(&index_id, &source_id).await
+                    }
+                    Some(SourceAction::Delete) => {
+                        QuickwitApi::delete_source(&index_id, &source_id).await
+                    }
+                };
+                busy.set(false);
+                confirming.set(None);
+                match result {
+                    Ok(()) => on_change.call(()),
+                    Err(e) => error.set(Some(e.to_string())),
+                }
+            });
+        }
+    });
+
+    let button_style = "margin-left: 6px; padding: 4px 8px; border: 1px solid #404040; border-radius: 4px; background: none; font-size: 12px; cursor: pointer;";
+
+    rsx! {
+        tr { style: "border-bottom: 1px solid #2a2a2a;",
+            td { style: "padding: 8px 12px; color: #f8f9fa;", "{source.source_id}" }
+            td { style: "padding: 8px 12px; color: #b0b0b0;", "{source.source_type}" }
+            td { style: "padding: 8px 12px; color: #b0b0b0;", "{source.input_format}" }
+            td { style: "padding: 8px 12px; color: #b0b0b0;", "{source.num_pipelines}" }
+            td { style: "padding: 8px 12px; color: #b0b0b0;", "{source.enabled}" }
+            td { style: "padding: 8px 12px; text-align: right; white-space: nowrap;",
+                if let Some(e) = error() {
+                    span { color: "#e57373", font_size: "12px", margin_right: "8px", "{e}" }
+                }
+                match confirming() {
+                    Some(action) => rsx! {
+                        span { color: "#b0b0b0", font_size: "12px",
+                            match action {
+                                SourceAction::ResetCheckpoint => "Reprocess from the start of the source?",
+                                SourceAction::Delete => "Delete this source and its checkpoint?",
+                            }
+                        }
+                        button {
+                            r#type: "button",
+                            style: "{button_style} color: #e57373;",
+                            disabled: busy(),
+                            onclick: move |_| run.call(Some(action)),
+                            "CONFIRM"
+                        }
+                        button {
+                            r#type: "button",
+                            style: "{button_style} color: #b0b0b0;",
+                            onclick: move |_| confirming.set(None),
+                            "CANCEL"
+                        }
+                    },
+                    None => rsx! {
+                        button {
+                            r#type: "button",
+                            style: "{button_style} color: #b0b0b0;",
+                            disabled: busy(),
+                            onclick: move |_| run.call(None),
+                            if source.enabled {
+                                "Disable"
+                            } else {
+                                "Enable"
+                            }
+                        }
+                        button {
+                            r#type: "button",
+                            style: "{button_style} color: #b0b0b0;",
+                            disabled: busy(),
+                            onclick: move |_| confirming.set(Some(SourceAction::ResetCheckpoint)),
+                            "Reset checkpoint"
+                        }
+                        if !source.is_internal() {
+                            button {
+                                r#type: "button",
+                                style: "{button_style} color: #e57373;",
+                                disabled: busy(),
+                                onclick: move |_| confirming.set(Some(SourceAction::Delete)),
+                                "Delete"
+                            }
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn AddSourceForm(index_id: String, on_change: EventHandler<()>) -> Element {
+    let mut form = use_signal(SourceForm::default);
+    let mut submitting = use_signal(|| false);
+    let mut submit_error: Signal<Option<String>> = use_signal(|| None);
+
+    let current = form.read().clone();
+    let errors = current.validate();
+    let (first_label, second_label) = current.kind.param_labels();
+    let can_submit = errors.is_empty() && !submitting();
+
+    rsx! {
+        div { padding: "12px 16px", border_top: "1px solid #404040",
+            Section { title: "Add source",
+                FormRow { label: "Type",
+                    select {
+                        style: INPUT_STYLE,
+                        onchange: move |e| {
+                            let kind = SourceKind::ALL
+                                .into_iter()
+                                .find(|kind| kind.to_string() == e.value())
+                                .unwrap_or_default();
+                            let mut form = form.write();
+                            form.kind = kind;
+                            form.first_param.clear();
+                            form.second_param.clear();
+                        },
+                        for kind in SourceKind::ALL {
+                            option {
+                                key: "{kind}",
+                                value: "{kind}",
+                                selected: kind == current.kind,
+                                "{kind}"
+                            }
+                        }
+                    }
+                }
+                FormRow { label: "Source id",
+                    input {
+                        style: "{INPUT_STYLE} width: 300px;",
+                        value: "{current.source_id}",
+                        oninput: move |e| form.write().source_id = e.value(),
+                    }
+                }
+                if !first_label.is_empty() {
+                    FormRow { label: first_label,
+                        input {
+                            style: "{INPUT_STYLE} width: 300px;",
+                            value: "{current.first_param}",
+                            oninput: move |e| form.write().first_param = e.value(),
+                        }
+                    }
+                }
+                if let Some(second_label) = second_label {
+                    FormRow { label: second_label,
+                        input {
+                            style: "{INPUT_STYLE} width: 300px;",
+                            value: "{current.second_param}",
+                            oninput: move |e| form.write().second_param = e.value(),
+                        }
+                    }
+                }
+                FormRow { label: "Input format",
+                    select {
+                        style: INPUT_STYLE,
+                        value: "{current.input_format}",
+                        onchange: move |e| form.write().input_format = e.value(),
+                        for format in INPUT_FORMATS {
+                            option { key: "{format}", value: format, "{format}" }
+                        }
+                    }
+                }
+                FormRow { label: "Pipelines",
+                    input {
+                        style: INPUT_STYLE,
+                        r#type: "number",
+                        min: "1",
+                        value: "{current.num_pipelines}",
+                        oninput: move |e| form.write().num_pipelines = e.value().parse().unwrap_or(0),
+                    }
+                }
+            }
+
+            if !errors.is_empty() {
+                ul { style: "color: #e57373; font-size: 13px; margin-bottom: 12px; padding-left: 16px;",
+                    for error in errors.iter() {
+                        li { key: "{error}", "{error}" }
+                    }
+                }
+            }
+            if let Some(error) = submit_error() {
+                p { style: "color: #e57373; font-size: 13px; margin-bottom: 12px;", "{error}" }
+            }
+            button {
+                r#type: "button",
+                border: "0",
+                border_radius: "4px",
+                padding: "6px 16px",
+                letter_spacing: "0.02857em",
+                color: if can_submit { "white" } else { "#4C4D4D" },
+                background_color: if can_submit { "rgb(25, 118, 210)" } else { "#151718" },
+                cursor: if can_submit { "pointer" } else { "not-allowed" },
+                disabled: !can_submit,
+                onclick: move |_| {
+                    let index_id = index_id.clone();
+                    async move {
+                        submitting.set(true);
+                        submit_error.set(None);
+                        let source = form.read().to_source();
+                        match QuickwitApi::create_source(&index_id, &source).await {
+                            Ok(_) => {
+                                form.set(SourceForm::default());
+                                on_change.call(());
+                            }
+                            Err(e) => submit_error.set(Some(e.to_string())),
+                        }
+                        submitting.set(false);
+                    }
+                },
+                "ADD SOURCE"
+            }
+        }
+    }
+}
+
+#[component]
+pub fn SourcesTab(index: Index, on_change: EventHandler<()>) -> Element {
+    let index_id = index.index_config.index_id.clone();
+
+    rsx! {
+        table { style: "width: 100%; border-collapse: collapse; font-size: 13px;",
+            thead {
+                tr { style: "border-bottom: 1px solid #404040;",
+                    th { style: "padding: 8px 12px; text-align: left; color: #f8f9fa;", "Source ID" }
+                    th { style: "padding: 8px 12px; text-align: left; color: #f8f9fa;", "Type" }
+                    th { style: "padding: 8px 12px; text-align: left; color: #f8f9fa;", "Input format" }
+                    th { style: "padding: 8px 12px; text-align: left; color: #f8f9fa;", "Pipelines" }
+                    th { style: "padding: 8px 12px; text-align: left; color: #f8f9fa;", "Enabled" }
+                    th { style: "padding: 8px 12px; text-align: right; color: #f8f9fa;", "Actions" }
+                }
+            }
+            tbody {
+                for source in index.sources {
+                    SourceRow {
+                        key: "{source.source_id}",
+                        index_id: index_id.clone(),
+                        source,
+                        on_change,
+                    }
+                }
+            }
+        }
+        AddSourceForm { index_id, on_change }
+    }
+}
@@ -0,0 +1,304 @@
+use crate::indexes::{format_bytes, format_utc};
+use crate::quickwit::QuickwitApi;
+use crate::quickwit::index::{Split, SplitState};
+use dioxus::prelude::*;
+
+const CHART_WIDTH: f64 = 600.0;
+const CHART_HEIGHT: f64 = 160.0;
+
+/// Nearest-rank percentile of an ascending slice.
+fn percentile(sorted: &[u64], p: f64) -> u64 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// Counts values into power-of-two buckets, returning `(lower bound, count)`
+/// for every bucket between the smallest and largest value.
+fn log2_histogram(values: &[u64]) -> Vec<(u64, usize)> {
+    let bucket = |value: u64| 63 - value.max(1).leading_zeros() as usize;
+    let (Some(min), Some(max)) = (values.iter().min(), values.iter().max()) else {
+        return Vec::new();
+    };
+
+    let first = bucket(*min);
+    let mut counts = vec![0; bucket(*max) - first + 1];
+    for value in values {
+        counts[bucket(*value) - first] += 1;
+    }
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(i, count)| (1u64 << (first + i), count))
+        .collect()
+}
+
+#[component]
+fn StatsTable(label: &'static str, values: Vec<u64>, unit: Unit) -> Element {
+    let mut sorted = values;
+    sorted.sort_unstable();
+
+    let stats = [
+        ("min", sorted.first().copied().unwrap_or_default()),
+        ("p50", percentile(&sorted, 50.0)),
+        ("p90", percentile(&sorted, 90.0)),
+        ("p99", percentile(&sorted, 99.0)),
+        ("max", sorted.last().copied().unwrap_or_default()),
+    ];
+
+    rsx! {
+        tr { style: "border-bottom: 1px solid #2a2a2a;",
+            td { style: "padding: 8px 12px; color: #b0b0b0;", "{label}" }
+            for (name , value) in stats {
+                td { key: "{name}", style: "padding: 8px 12px; color: #f8f9fa;", "{unit.format(value)}" }
+            }
+        }
+    }
+}
+
+#[component]
+fn Histogram(title: &'static str, values: Vec<u64>, unit: Unit) -> Element {
+    let buckets = log2_histogram(&values);
+    let max_count = buckets
+        .iter()
+        .map(|(_, count)| *count)
+        .max()
+        .unwrap_or(1)
+        .max(1);
+    let bar_width = CHART_WIDTH / buckets.len().max(1) as f64;
+
+    rsx! {
+        div { flex: 1, min_width: "300px",
+            p { color: "#b0b0b0", font_size: "13px", margin_bottom: "6px", "{title}" }
+            svg {
+                width: "100%",
+                height: "{CHART_HEIGHT + 20.0}",
+                view_box: "0 -14 {CHART_WIDTH} {CHART_HEIGHT + 34.0}",
+                for (i , (lower , count)) in buckets.iter().copied().enumerate() {
+                    g { key: "{lower}",
+                        rect {
+                            x: "{i as f64 * bar_width + 1.0}",
+                            y: "{CHART_HEIGHT - count as f64 / max_count as f64 * CHART_HEIGHT}",
+                            width: "{(bar_width - 2.0).max(1.0)}",
+                            height: "{count as f64 / max_count as f64 * CHART_HEIGHT}",
+                            fill: "rgb(25, 118, 210)",
+                        }
+                        if count > 0 {
+                            text {
+                                x: "{i as f64 * bar_width + bar_width / 2.0}",
+                                y: "{CHART_HEIGHT - count as f64 / max_count as f64 * CHART_HEIGHT - 4.0}",
+                                fill: "#b0b0b0",
+                                font_size: "10",
+                                text_anchor: "middle",
+                                "{count}"
+                            }
+                        }
+                        text {
+                            x: "{i as f64 * bar_width + bar_width / 2.0}",
+                            y: "{CHART_HEIGHT + 14.0}",
+                            fill: "#5F6060",
+                            font_size: "10",
+                            text_anchor: "middle",
+                            "{unit.format(lower)}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn PublishedBytesTimeline(splits: Vec<Split>, expected_total: Option<u64>) -> Element {
+    let mut points: Vec<(i64, u64)> = splits
+        .iter()
+        .map(|split| {
+            (
+                split.publish_timestamp.unwrap_or(split.create_timestamp),
+                split.uncompressed_docs_size_in_bytes,
+            )
+        })
+        .collect();
+    points.sort_by_key(|(at, _)| *at);
+
+    let mut total = 0;
+    let cumulative: Vec<(i64, u64)> = points
+        .into_iter()
+        .map(|(at, bytes)| {
+            total += bytes;
+            (at, total)
+        })
+        .collect();
+
+    let (Some(first), Some(last)) = (cumulative.first(), cumulative.last()) else {
+        return rsx! {};
+    };
+    let (start, end, max) = (first.0, last.0, last.1.max(1));
+    let span = (end - start).max(1) as f64;
+    let polyline = cumulative
+        .iter()
+        .map(|(at, bytes)| {
+            format!(
+                "{:.1},{:.1}",
+                (at - start) as f64 / span * CHART_WIDTH,
+                CHART_HEIGHT - *bytes as f64 / max as f64 * CHART_HEIGHT
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    rsx! {
+        div {
+            p { color: "#b0b0b0", font_size: "13px", margin_bottom: "6px",
+                "Cumulative published bytes (uncompressed): {format_bytes(total)}"
+                if let Some(expected) = expected_total {
+                    " · describe reports {format_bytes(expected)}"
+                }
+            }
+            svg {
+                width: "100%",
+                height: "{CHART_HEIGHT + 20.0}",
+                view_box: "0 0 {CHART_WIDTH} {CHART_HEIGHT + 20.0}",
+                preserve_aspect_ratio: "none",
+                polyline {
+                    points: "{polyline}",
+                    fill: "none",
+                    stroke: "rgb(25, 118, 210)",
+                    stroke_width: "2",
+                }
+                text {
+                    x: "0",
+                    y: "{CHART_HEIGHT + 14.0}",
+                    fill: "#5F6060",
+                    font_size: "10",
+                    "{format_utc(start)}"
+                }
+                text {
+                    x: "{CHART_WIDTH}",
+                    y: "{CHART_HEIGHT + 14.0}",
+                    fill: "#5F6060",
+                    font_size: "10",
+                    text_anchor: "end",
+                    "{format_utc(end)}"
+                }
+            }
+        }
+    }
+}
+
+/// How the values of a table or chart are displayed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Unit {
+    Bytes,
+    Count,
+}
+
+impl Unit {
+    fn format(self, value: u64) -> String {
+        match self {
+            Unit::Bytes => format_bytes(value),
+            Unit::Count => value.to_string(),
+        }
+    }
+}
+
+#[component]
+pub fn SplitStatsTab(index_id: String) -> Element {
+    let splits = use_resource(use_reactive!(|index_id| async move {
+        QuickwitApi::index_splits(&index_id)
+            .await
+            .map_err(|e| e.to_string())
+    }));
+    let description = use_resource(use_reactive!(|index_id| async move {
+        QuickwitApi::index_describe(&index_id).await.ok()
+    }));
+
+    let response = match splits() {
+        Some(Ok(response)) => response,
+        Some(Err(e)) => {
+            return rsx! {
+                p { style: "padding: 8px 12px; color: #e57373;", "{e}" }
+            };
+        }
+        None => {
+            return rsx! {
+                p { style: "padding: 8px 12px; color: #b0b0b0;", "Loading..." }
+            };
+        }
+    };
+
+    // Merge policy behaviour is judged on what searchers actually see.
+    let published: Vec<Split> = response
+        .splits
+        .into_iter()
+        .filter(|split| split.split_state == SplitState::Published)
+        .collect();
+    let sizes: Vec<u64> = published
+        .iter()
+        .map(|split| split.uncompressed_docs_size_in_bytes)
+        .collect();
+    let docs: Vec<u64> = published.iter().map(|split| split.num_docs).collect();
+
+    rsx! {
+        div { padding: "8px 12px", display: "flex", flex_direction: "column", gap: "20px",
+            table { style: "width: 100%; border-collapse: collapse; font-size: 13px;",
+                thead {
+                    tr { style: "border-bottom: 1px solid #404040;",
+                        th { style: "padding: 8px 12px; text-align: left; color: #f8f9fa;",
+                            "{published.len()} published splits"
+                        }
+                        for name in ["min", "p50", "p90", "p99", "max"] {
+                            th {
+                                key: "{name}",
+                                style: "padding: 8px 12px; text-align: left; color: #f8f9fa;",
+                                "{name}"
+                            }
+                        }
+                    }
+                }
+                tbody {
+                    StatsTable { label: "Size (uncompressed)", values: sizes.clone(), unit: Unit::Bytes }
+                    StatsTable { label: "Documents", values: docs.clone(), unit: Unit::Count }
+                }
+            }
+            div { display: "flex", gap: "24px", flex_wrap: "wrap",
+                Histogram { title: "Split sizes", values: sizes, unit: Unit::Bytes }
+                Histogram { title: "Documents per split", values: docs, unit: Unit::Count }
+            }
+            PublishedBytesTimeline {
+                splits: published,
+                expected_total: description()
+                    .flatten()
+                    .map(|description| description.size_published_docs_uncompressed),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentile_uses_nearest_rank() {
+        let sorted: Vec<u64> = (1..=10).collect();
+        assert_eq!(percentile(&sorted, 50.0), 5);
+        assert_eq!(percentile(&sorted, 90.0), 9);
+        assert_eq!(percentile(&sorted, 99.0), 10);
+        assert_eq!(percentile(&sorted, 0.0), 1);
+        assert_eq!(percentile(&[7], 50.0), 7);
+        assert_eq!(percentile(&[], 50.0), 0);
+    }
+
+    #[test]
+    fn log2_histogram_fills_buckets_between_min_and_max() {
+        assert_eq!(
+            log2_histogram(&[3, 2, 20, 31]),
+            vec![(2, 2), (4, 0), (8, 0), (16, 2)]
+        );
+        assert_eq!(log2_histogram(&[0, 1]), vec![(1, 2)]);
+        assert!(log2_histogram(&[]).is_empty());
+    }
+}
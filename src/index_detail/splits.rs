@@ -0,0 +1,411 @@
+use crate::indexes::{format_bytes, format_utc};
+use crate::quickwit::QuickwitApi;
+use crate::quickwit::index::{Split, SplitMaturity, SplitState};
+use dioxus::prelude::*;
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SplitColumn {
+    SplitId,
+    State,
+    NumDocs,
+    Size,
+    Start,
+    End,
+    Created,
+    Updated,
+    Published,
+    DeleteOpstamp,
+}
+
+impl SplitColumn {
+    const ALL: [SplitColumn; 10] = [
+        SplitColumn::SplitId,
+        SplitColumn::State,
+        SplitColumn::NumDocs,
+        SplitColumn::Size,
+        SplitColumn::Start,
+        SplitColumn::End,
+        SplitColumn::Created,
+        SplitColumn::Updated,
+        SplitColumn::Published,
+        SplitColumn::DeleteOpstamp,
+    ];
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            SplitColumn::SplitId => "Split ID",
+            SplitColumn::State => "State",
+            SplitColumn::NumDocs => "Docs",
+            SplitColumn::Size => "Size",
+            SplitColumn::Start => "Time start",
+            SplitColumn::End => "Time end",
+            SplitColumn::Created => "Created",
+            SplitColumn::Updated => "Updated",
+            SplitColumn::Published => "Published",
+            SplitColumn::DeleteOpstamp => "Delete opstamp",
+        }
+    }
+
+    fn compare(&self, a: &Split, b: &Split) -> Ordering {
+        match self {
+            SplitColumn::SplitId => a.split_id.cmp(&b.split_id),
+            SplitColumn::State => a.split_state.as_str().cmp(b.split_state.as_str()),
+            SplitColumn::NumDocs => a.num_docs.cmp(&b.num_docs),
+            SplitColumn::Size => a
+                .uncompressed_docs_size_in_bytes
+                .cmp(&b.uncompressed_docs_size_in_bytes),
+            SplitColumn::Start => a
+                .time_range
+                .map(|r| r.start)
+                .cmp(&b.time_range.map(|r| r.start)),
+            SplitColumn::End => a
+                .time_range
+                .map(|r| r.end)
+                .cmp(&b.time_range.map(|r| r.end)),
+            SplitColumn::Created => a.create_timestamp.cmp(&b.create_timestamp),
+            SplitColumn::Updated => a.update_timestamp.cmp(&b.update_timestamp),
+            SplitColumn::Published => a.publish_timestamp.cmp(&b.publish_timestamp),
+            SplitColumn::DeleteOpstamp => a.delete_opstamp.cmp(&b.delete_opstamp),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum MaturityFilter {
+    All,
+    Mature,
+    Immature,
+}
+
+fn format_optional_utc(timestamp: Option<i64>) -> String {
+    timestamp.map(format_utc).unwrap_or_else(|| "-".to_string())
+}
+
+fn format_maturity(maturity: Option<&SplitMaturity>) -> String {
+    match maturity {
+        Some(SplitMaturity::Mature) => "mature".to_string(),
+        Some(SplitMaturity::Immature {
+            maturation_period_millis,
+        }) => format!("immature ({}h)", maturation_period_millis / 3_600_000),
+        None => "-".to_string(),
+    }
+}
+
+/// Stacks split time ranges into lanes so that overlapping splits end up on
+/// different rows. Returns the lane of each split and the number of lanes.
+fn assign_lanes(ranges: &[(i64, i64)]) -> (Vec<usize>, usize) {
+    let mut order: Vec<usize> = (0..ranges.len()).collect();
+    order.sort_by_key(|&i| ranges[i].0);
+
+    let mut lane_ends: Vec<i64> = Vec::new();
+    let mut lanes = vec![0; ranges.len()];
+    for i in order {
+        let (start, end) = ranges[i];
+        match lane_ends.iter().position(|&lane_end| lane_end < start) {
+            Some(lane) => {
+                lane_ends[lane] = end;
+                lanes[i] = lane;
+            }
+            None => {
+                lanes[i] = lane_ends.len();
+                lane_ends.push(end);
+            }
+        }
+    }
+    (lanes, lane_ends.len())
+}
+
+/// Time intervals between `min` and `max` that no split covers.
+fn find_gaps(ranges: &[(i64, i64)]) -> Vec<(i64, i64)> {
+    let mut sorted = ranges.to_vec();
+    sorted.sort();
+
+    let mut gaps = Vec::new();
+    let mut covered_until: Option<i64> = None;
+    for (start, end) in sorted {
+        if let Some(until) = covered_until
+            && start > until + 1
+        {
+            gaps.push((until, start));
+        }
+        covered_until = Some(covered_until.map_or(end, |until| until.max(end)));
+    }
+    gaps
+}
+
+#[component]
+fn SplitTimeline(splits: Vec<Split>) -> Element {
+    const WIDTH: f64 = 1000.0;
+    const LANE_HEIGHT: f64 = 10.0;
+    const AXIS_HEIGHT: f64 = 20.0;
+
+    let ranges: Vec<(i64, i64)> = splits
+        .iter()
+        .filter_map(|split| split.time_range.map(|r| (r.start, r.end)))
+        .collect();
+
+    let (Some(min), Some(max)) = (
+        ranges.iter().map(|r| r.0).min(),
+        ranges.iter().map(|r| r.1).max(),
+    ) else {
+        return rsx! {
+            p { style: "padding: 8px 12px; color: #b0b0b0;", "No split has a time range." }
+        };
+    };
+
+    let span = (max - min).max(1) as f64;
+    let x = move |ts: i64| (ts - min) as f64 / span * WIDTH;
+    let (lanes, num_lanes) = assign_lanes(&ranges);
+    let gaps = find_gaps(&ranges);
+    let height = num_lanes as f64 * LANE_HEIGHT + AXIS_HEIGHT;
+
+    rsx! {
+        div { padding: "8px 12px",
+            p { color: "#b0b0b0", font_size: "13px", margin_bottom: "6px",
+                "{ranges.len()} splits over {num_lanes} lanes · {gaps.len()} gaps"
+            }
+            svg {
+                width: "100%",
+                height: "{height}",
+                view_box: "0 0 {WIDTH} {height}",
+                preserve_aspect_ratio: "none",
+                for (start , end) in gaps.iter().copied() {
+                    rect {
+                        x: "{x(start)}",
+                        y: "0",
+                        width: "{(x(end) - x(start)).max(1.0)}",
+                        height: "{height - AXIS_HEIGHT}",
+                        fill: "rgba(229, 72, 77, 0.25)",
+                    }
+                }
+                for (i , (start , end)) in ranges.iter().copied().enumerate() {
+                    rect {
+                        x: "{x(start)}",
+                        y: "{lanes[i] as f64 * LANE_HEIGHT + 1.0}",
+                        width: "{(x(end) - x(start)).max(1.0)}",
+                        height: "{LANE_HEIGHT - 2.0}",
+                        fill: if lanes[i] == 0 { "rgb(25, 118, 210)" } else { "#ffeaa7" },
+                    }
+                }
+                text {
+                    x: "0",
+                    y: "{height - 4.0}",
+                    fill: "#5F6060",
+                    font_size: "11",
+                    "{format_utc(min)}"
+                }
+                text {
+                    x: "{WIDTH}",
+                    y: "{height - 4.0}",
+                    fill: "#5F6060",
+                    font_size: "11",
+                    text_anchor: "end",
+                    "{format_utc(max)}"
+                }
+            }
+            if !gaps.is_empty() {
+                ul { list_style: "none", margin_top: "6px", font_size: "12px", color: "#b0b0b0",
+                    for (start , end) in gaps.iter().copied().take(10) {
+                        li { "Gap: {format_utc(start)} - {format_utc(end)}" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn SplitsTab(index_id: String) -> Element {
+    let mut sort_column = use_signal(|| SplitColumn::Created);
+    let mut sort_descending = use_signal(|| true);
+    let mut state_filter: Signal<Option<SplitState>> = use_signal(|| None);
+    let mut maturity_filter = use_signal(|| MaturityFilter::All);
+    let mut search = use_signal(String::new);
+
+    let splits = use_resource(use_reactive!(|index_id| async move {
+        QuickwitApi::index_splits(&index_id)
+            .await
+            .map_err(|e| e.to_string())
+    }));
+
+    let filtered = move || -> Vec<Split> {
+        let Some(Ok(response)) = splits() else {
+            return Vec::new();
+        };
+        let search = search().to_lowercase();
+        let mut filtered: Vec<Split> = response
+            .splits
+            .into_iter()
+            .filter(|split| state_filter().is_none_or(|state| split.split_state == state))
+            .filter(|split| match maturity_filter() {
+                MaturityFilter::All => true,
+                MaturityFilter::Mature => split.maturity == Some(SplitMaturity::Mature),
+                MaturityFilter::Immature => {
+                    matches!(split.maturity, Some(SplitMaturity::Immature { .. }))
+                }
+            })
+            .filter(|split| {
+                search.is_empty()
+                    || split.split_id.to_lowercase().contains(&search)
+                    || split.node_id.to_lowercase().contains(&search)
+                    || split.source_id.to_lowercase().contains(&search)
+            })
+            .collect();
+        filtered.sort_by(|a, b| {
+            let ordering = sort_column().compare(a, b);
+            if sort_descending() {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+        filtered
+    };
+
+    let cell = "padding: 6px 8px; color: #b0b0b0; white-space: nowrap;";
+    let control = "background-color: #2d2d2d; color: #f8f9fa; border: 1px solid #404040; border-radius: 4px; padding: 6px 12px; outline: none;";
+
+    match &*splits.read() {
+        Some(Ok(_)) => {}
+        Some(Err(e)) => {
+            return rsx! {
+                p { style: "padding: 8px 12px; color: #e57373;", "{e}" }
+            };
+        }
+        None => {
+            return rsx! {
+                p { style: "padding: 8px 12px; color: #b0b0b0;", "Loading..." }
+            };
+        }
+    }
+
+    let rows = filtered();
+
+    rsx! {
+        div { display: "flex", gap: "12px", padding: "8px 12px", align_items: "center",
+            input {
+                style: control,
+                placeholder: "Filter by split, node or source",
+                value: "{search}",
+                oninput: move |e| search.set(e.value()),
+            }
+            select {
+                style: control,
+                onchange: move |e| {
+                    state_filter
+                        .set(
+                            [SplitState::Staged, SplitState::Published, SplitState::MarkedForDeletion]
+                                .into_iter()
+                                .find(|state| state.as_str() == e.value()),
+                        )
+                },
+                option { value: "All", "All states" }
+                for state in [SplitState::Staged, SplitState::Published, SplitState::MarkedForDeletion] {
+                    option { key: "{state.as_str()}", value: "{state.as_str()}", "{state.as_str()}" }
+                }
+            }
+            select {
+                style: control,
+                onchange: move |e| {
+                    maturity_filter
+                        .set(
+                            match e.value().as_str() {
+                                "Mature" => MaturityFilter::Mature,
+                                "Immature" => MaturityFilter::Immature,
+                                _ => MaturityFilter::All,
+                            },
+                        )
+                },
+                option { value: "All", "All maturities" }
+                option { value: "Mature", "Mature" }
+                option { value: "Immature", "Immature" }
+            }
+            span { color: "#5F6060", font_size: "13px", "{rows.len()} splits" }
+        }
+
+        SplitTimeline { splits: rows.clone() }
+
+        div { overflow_x: "auto",
+            table { style: "width: 100%; border-collapse: collapse; font-size: 12px;",
+                thead {
+                    tr { style: "border-bottom: 1px solid #404040;",
+                        for column in SplitColumn::ALL {
+                            th {
+                                key: "{column.as_str()}",
+                                style: "padding: 6px 8px; text-align: left; color: #f8f9fa; cursor: pointer; white-space: nowrap; user-select: none;",
+                                onclick: move |_| {
+                                    if sort_column() == column {
+                                        sort_descending.toggle();
+                                    } else {
+                                        sort_column.set(column);
+                                        sort_descending.set(true);
+                                    }
+                                },
+                                "{column.as_str()}"
+                                if sort_column() == column {
+                                    if sort_descending() {
+                                        " ▼"
+                                    } else {
+                                        " ▲"
+                                    }
+                                }
+                            }
+                        }
+                        th { style: "padding: 6px 8px; text-align: left; color: #f8f9fa;", "Maturity" }
+                        th { style: "padding: 6px 8px; text-align: left; color: #f8f9fa;", "Node" }
+                        th { style: "padding: 6px 8px; text-align: left; color: #f8f9fa;", "Footer" }
+                    }
+                }
+                tbody {
+                    for split in rows {
+                        tr { key: "{split.split_id}", style: "border-bottom: 1px solid #2a2a2a;",
+                            td { style: "padding: 6px 8px; color: #f8f9fa; font-family: monospace;",
+                                "{split.split_id}"
+                            }
+                            td { style: cell, "{split.split_state.as_str()}" }
+                            td { style: cell, "{split.num_docs}" }
+                            td { style: cell, "{format_bytes(split.uncompressed_docs_size_in_bytes)}" }
+                            td { style: cell, "{format_optional_utc(split.time_range.map(|r| r.start))}" }
+                            td { style: cell, "{format_optional_utc(split.time_range.map(|r| r.end))}" }
+                            td { style: cell, "{format_utc(split.create_timestamp)}" }
+                            td { style: cell, "{format_utc(split.update_timestamp)}" }
+                            td { style: cell, "{format_optional_utc(split.publish_timestamp)}" }
+                            td { style: cell, "{split.delete_opstamp}" }
+                            td { style: cell, "{format_maturity(split.maturity.as_ref())}" }
+                            td { style: cell, "{split.node_id}" }
+                            td { style: cell, "{format_bytes(split.footer_size())}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn assign_lanes_reuses_lanes_once_a_split_ends() {
+        let ranges = [(20, 29), (0, 9), (10, 19)];
+        assert_eq!(assign_lanes(&ranges), (vec![0, 0, 0], 1));
+    }
+
+    #[test]
+    fn assign_lanes_stacks_overlapping_splits() {
+        // Time ranges are inclusive, so splits sharing an end second overlap.
+        let ranges = [(0, 10), (10, 20), (5, 12), (21, 30)];
+        assert_eq!(assign_lanes(&ranges), (vec![0, 2, 1, 0], 3));
+        assert_eq!(assign_lanes(&[]), (vec![], 0));
+    }
+
+    #[test]
+    fn find_gaps_between_uncovered_seconds() {
+        let ranges = [(30, 40), (0, 9), (10, 15), (2, 20)];
+        assert_eq!(find_gaps(&ranges), vec![(20, 30)]);
+        assert!(find_gaps(&[(0, 9), (10, 19)]).is_empty());
+        assert!(find_gaps(&[]).is_empty());
+    }
+}
@@ -1,11 +1,15 @@
+use crate::auto_refresh::{AutoRefreshSelect, RefreshInterval, use_auto_refresh};
+use crate::index_actions::{IndexAction, IndexActionDialog};
+use crate::metadata::use_metadata_cache;
 use crate::{Route, icons, quickwit};
 use chrono::{NaiveDateTime, TimeZone, Utc};
 use dioxus::prelude::*;
 use quickwit::index::{Index, SplitState};
 
 #[component]
-fn IndexRow(index: quickwit::index::Index) -> Element {
+fn IndexRow(index: quickwit::index::Index, mut refresh_tick: Signal<u64>) -> Element {
     let mut is_expanded = use_signal(|| false);
+    let mut action: Signal<Option<IndexAction>> = use_signal(|| None);
 
     rsx! {
         tr {
@@ -23,7 +27,14 @@ fn IndexRow(index: quickwit::index::Index) -> Element {
                 }
             }
             th { style: "padding: 12px 16px; font-weight: 500; color: #f8f9fa; font-size: 13px; text-align: left;",
-                "{index.index_config.index_id}"
+                Link {
+                    to: Route::IndexDetail {
+                        index_id: index.index_config.index_id.clone(),
+                    },
+                    onclick: |e: MouseEvent| e.stop_propagation(),
+                    style: "color: #f8f9fa; text-decoration: underline; text-decoration-color: #5F6060;",
+                    "{index.index_config.index_id}"
+                }
             }
             td { style: "padding: 12px 16px; font-weight: 500; color: #f8f9fa; font-size: 13px; text-align: left;",
                 "{index.index_config.index_uri}"
@@ -34,40 +45,114 @@ fn IndexRow(index: quickwit::index::Index) -> Element {
             td { style: "padding: 12px 16px; font-weight: 500; color: #f8f9fa; font-size: 13px; text-align: left;",
                 "{index.sources.len()}"
             }
+            td { style: "padding: 12px 16px; text-align: right; white-space: nowrap;",
+                for choice in IndexAction::ALL {
+                    button {
+                        key: "{choice}",
+                        r#type: "button",
+                        margin_left: "6px",
+                        padding: "4px 8px",
+                        border: "1px solid #404040",
+                        border_radius: "4px",
+                        background: "none",
+                        color: if choice == IndexAction::Delete { "#e57373" } else { "#b0b0b0" },
+                        font_size: "12px",
+                        cursor: "pointer",
+                        onclick: move |e: MouseEvent| {
+                            e.stop_propagation();
+                            action.set(Some(choice));
+                        },
+                        "{choice}"
+                    }
+                }
+                if let Some(current) = action() {
+                    IndexActionDialog {
+                        index_id: index.index_config.index_id.clone(),
+                        action: current,
+                        on_close: move |_| action.set(None),
+                        on_done: move |_| {
+                            action.set(None);
+                            refresh_tick += 1;
+                        },
+                    }
+                }
+            }
         }
 
         if is_expanded() {
-            IndexDetailsRow { index: index.clone() }
+            IndexDetailsRow { index: index.clone(), refresh_tick }
         }
     }
 }
 
+/// Delay before the second throughput sample of a freshly expanded row.
+const THROUGHPUT_SAMPLE_DELAY_MS: u32 = 5_000;
+
 #[component]
-fn IndexDetailsRow(index: Index) -> Element {
+fn IndexDetailsRow(index: Index, refresh_tick: Signal<u64>) -> Element {
     let index_id = index.index_config.index_id.clone();
 
     let data = use_resource({
         let index_id_clone = index_id.clone();
         move || {
             let value = index_id_clone.clone();
-            async move { quickwit::QuickwitApi::index_describe(&value).await.unwrap() }
+            refresh_tick();
+            async move { quickwit::QuickwitApi::index_describe(&value).await.ok() }
         }
     });
 
-    let indexing =
-        use_resource(move || async move { quickwit::QuickwitApi::indexing().await.unwrap() });
+    let mut last_sample: Signal<Option<(i64, u64, u64)>> = use_signal(|| None);
+    let mut throughput: Signal<Option<(f64, f64)>> = use_signal(|| None);
+    // Bumped once shortly after the first sample, so throughput shows even
+    // with auto-refresh off.
+    let mut sample_tick = use_signal(|| 0u64);
+
+    let indexing = use_resource({
+        let index_id_clone = index_id.clone();
+        move || {
+            let value = index_id_clone.clone();
+            refresh_tick();
+            sample_tick();
+            async move {
+                let stats = quickwit::QuickwitApi::index_indexing_stats(&value)
+                    .await
+                    .ok()?;
+
+                // Throughput is derived from the counters between two refreshes.
+                let now = Utc::now().timestamp_millis();
+                let (docs, bytes) = (stats.num_docs_processed(), stats.total_bytes_processed);
+                if let Some((at, prev_docs, prev_bytes)) = *last_sample.peek()
+                    && now > at
+                {
+                    let secs = (now - at) as f64 / 1000.0;
+                    throughput.set(Some((
+                        docs.saturating_sub(prev_docs) as f64 / secs,
+                        bytes.saturating_sub(prev_bytes) as f64 / secs,
+                    )));
+                } else {
+                    spawn(async move {
+                        gloo_timers::future::TimeoutFuture::new(THROUGHPUT_SAMPLE_DELAY_MS).await;
+                        sample_tick += 1;
+                    });
+                }
+                last_sample.set(Some((now, docs, bytes)));
+                Some(stats)
+            }
+        }
+    });
 
     let splits = use_resource({
         let index_id_clone = index_id.clone();
         move || {
             let value = index_id_clone.clone();
-            async move { quickwit::QuickwitApi::index_splits(&value).await.unwrap() }
+            refresh_tick();
+            async move { quickwit::QuickwitApi::index_splits(&value).await.ok() }
         }
     });
 
     rsx! {
         tr { style: "border-bottom: 1px solid #404040; background: #2a2a2a;",
-            td { colspan: "5", style: "padding: 0; color: #f8f9fa;",
+            td { colspan: "6", style: "padding: 0; color: #f8f9fa;",
                 table { style: "width: 100%; border-collapse: collapse; font-size: 13px;",
                     tbody {
                         tr { style: "background-color: #1e1e1e;",
@@ -90,9 +175,10 @@ fn IndexDetailsRow(index: Index) -> Element {
                             }
                             td { style: "padding: 8px 12px; color: #f8f9fa;",
                                 match data() {
-                                    Some(desc) => rsx! {
+                                    Some(Some(desc)) => rsx! {
                                     "{desc.num_published_docs}"
                                     },
+                                    Some(None) => rsx! { "Unavailable" },
                                     None => rsx! { "Loading..." },
                                 }
                             }
@@ -103,9 +189,10 @@ fn IndexDetailsRow(index: Index) -> Element {
                             }
                             td { style: "padding: 8px 12px; color: #f8f9fa;",
                                 match data() {
-                                    Some(desc) => rsx! {
+                                    Some(Some(desc)) => rsx! {
                                     "{format_bytes(desc.size_published_docs_uncompressed)}"
                                     },
+                                    Some(None) => rsx! { "Unavailable" },
                                     None => rsx! { "Loading..." },
                                 }
                             }
@@ -116,9 +203,10 @@ fn IndexDetailsRow(index: Index) -> Element {
                             }
                             td { style: "padding: 8px 12px; color: #f8f9fa;",
                                 match data() {
-                                    Some(desc) => rsx! {
+                                    Some(Some(desc)) => rsx! {
                                     "{desc.num_published_splits}"
                                     },
+                                    Some(None) => rsx! { "Unavailable" },
                                     None => rsx! { "Loading..." },
                                 }
                             }
@@ -129,35 +217,92 @@ fn IndexDetailsRow(index: Index) -> Element {
                             }
                             td { style: "padding: 8px 12px; color: #f8f9fa;",
                                 match data() {
-                                    Some(desc) => rsx! {
+                                    Some(Some(desc)) => rsx! {
                                     "{format_bytes(desc.size_published_splits)}"
                                     },
+                                    Some(None) => rsx! { "Unavailable" },
                                     None => rsx! { "Loading..." },
                                 }
                             }
                         }
                         tr { style: "background-color: #1e1e1e;",
                             td { style: "padding: 8px 12px; color: #b0b0b0;",
-                                "Number of staged splits:"
+                                "Documents processed:"
+                            }
+                            td { style: "padding: 8px 12px; color: #f8f9fa;",
+                                match indexing() {
+                                    Some(Some(stats)) => {
+                                        let unreached_nodes = stats.unreached_nodes.join(", ");
+                                        rsx! {
+                                            "{stats.num_docs_processed()} ({stats.num_invalid_docs} invalid)"
+                                            if !unreached_nodes.is_empty() {
+                                                span { color: "#ffb74d", font_size: "12px",
+                                                    " — excludes unreachable indexers: {unreached_nodes}"
+                                                }
+                                            }
+                                        }
+                                    }
+                                    Some(None) => rsx! { "Unavailable" },
+                                    None => rsx! { "Loading..." },
+                                }
+                            }
+                        }
+                        tr { style: "background-color: #2a2a2a;",
+                            td { style: "padding: 8px 12px; color: #b0b0b0;",
+                                "Bytes processed:"
                             }
                             td { style: "padding: 8px 12px; color: #f8f9fa;",
                                 match indexing() {
-                                    Some(indexing_data) => rsx! {
-                                    "{indexing_data.num_staged_splits.unwrap_or_default()}"
+                                    Some(Some(stats)) => rsx! {
+                                    "{format_bytes(stats.total_bytes_processed)}"
                                     },
+                                    Some(None) => rsx! { "Unavailable" },
                                     None => rsx! { "Loading..." },
                                 }
                             }
                         }
+                        tr { style: "background-color: #1e1e1e;",
+                            td { style: "padding: 8px 12px; color: #b0b0b0;",
+                                "Indexing throughput:"
+                            }
+                            td { style: "padding: 8px 12px; color: #f8f9fa;",
+                                match throughput() {
+                                    Some((docs_per_sec, bytes_per_sec)) => rsx! {
+                                    "{docs_per_sec:.1} docs/s, {format_bytes(bytes_per_sec as u64)}/s"
+                                    },
+                                    None => rsx! { "Measuring..." },
+                                }
+                            }
+                        }
                         tr { style: "background-color: #2a2a2a;",
+                            td { style: "padding: 8px 12px; color: #b0b0b0;",
+                                "Indexing pipelines:"
+                            }
+                            td { style: "padding: 8px 12px; color: #f8f9fa;",
+                                match indexing() {
+                                    Some(Some(stats)) => rsx! {
+                                        "{stats.num_pipelines()}"
+                                        for (source_id , count) in stats.pipelines_by_source() {
+                                            span { key: "{source_id}", color: "#b0b0b0", margin_left: "12px",
+                                                "{source_id}: {count}"
+                                            }
+                                        }
+                                    },
+                                    Some(None) => rsx! { "Unavailable" },
+                                    None => rsx! { "Loading..." },
+                                }
+                            }
+                        }
+                        tr { style: "background-color: #1e1e1e;",
                             td { style: "padding: 8px 12px; color: #b0b0b0;",
                                 "Number of splits marked for deletion:"
                             }
                             td { style: "padding: 8px 12px; color: #f8f9fa;",
                                 match splits() {
-                                    Some(splits) => rsx! {
+                                    Some(Some(splits)) => rsx! {
                                     "{splits.splits.iter().filter(|split| split.split_state == SplitState::MarkedForDeletion).count()}"
                                     },
+                                    Some(None) => rsx! { "Unavailable" },
                                     None => rsx! { "Loading..." },
                                 }
                             }
@@ -171,14 +316,36 @@ fn IndexDetailsRow(index: Index) -> Element {
 
 #[component]
 pub fn Indexes() -> Element {
-    let data = use_resource(|| async { quickwit::QuickwitApi::indexes().await.unwrap() });
+    let mut refresh_tick = use_signal(|| 0u64);
+    let refresh_interval = use_signal(RefreshInterval::default);
+
+    let metadata = use_metadata_cache();
+
+    use_auto_refresh(refresh_interval, move |_| {
+        refresh_tick += 1;
+        metadata.refresh();
+    });
 
     let navigator = use_navigator();
 
     rsx! {
         div { height: "100%", padding: "20px",
-            h1 { style: "font-size: 14px; font-weight: 700; margin-bottom: 16px; color: #f8f9fa;",
-                "Indexes"
+            div {
+                display: "flex",
+                align_items: "center",
+                justify_content: "space-between",
+                margin_bottom: "16px",
+                h1 { style: "font-size: 14px; font-weight: 700; color: #f8f9fa;",
+                    "Indexes"
+                }
+                div { display: "flex", align_items: "center", gap: "12px",
+                    AutoRefreshSelect { interval: refresh_interval }
+                    Link {
+                        to: Route::CreateIndex {},
+                        style: "border-radius: 4px; padding: 6px 16px; letter-spacing: 0.02857em; color: white; background-color: rgb(25, 118, 210); font-size: 13px; text-decoration: none;",
+                        "CREATE INDEX"
+                    }
+                }
             }
             div { style: "background: #1e1e1e; box-shadow: 0 1px 3px rgba(0,0,0,0.3);",
                 table { style: "width: 100%; border-collapse: collapse; background: #181a1b;",
@@ -199,19 +366,27 @@ pub fn Indexes() -> Element {
                             th { style: "padding: 12px 16px; text-align: left; font-weight: 600; color: #f8f9fa; font-size: 13px;",
                                 "Sources"
                             }
+                            th { style: "padding: 12px 16px; text-align: right; font-weight: 600; color: #f8f9fa; font-size: 13px;",
+                                "Actions"
+                            }
                         }
                     }
                     tbody {
                         {
-                            match data.read().as_ref() {
-                                Some(indexes) => rsx! {
+                            match metadata.indexes() {
+                                Some(Ok(indexes)) => rsx! {
                                     for index in indexes {
-                                        IndexRow { index: index.clone() }
+                                        IndexRow { index: index.clone(), refresh_tick }
+                                    }
+                                },
+                                Some(Err(e)) => rsx! {
+                                    tr {
+                                        td { colspan: "6", style: "padding: 12px 16px; color: #e57373;", "{e}" }
                                     }
                                 },
-                                _ => rsx! {
+                                None => rsx! {
                                     tr {
-                                        td { colspan: "5", style: "padding: 12px 16px; color: #b0b0b0;", "Loading..." }
+                                        td { colspan: "6", style: "padding: 12px 16px; color: #b0b0b0;", "Loading..." }
                                     }
                                 },
                             }
@@ -223,7 +398,7 @@ pub fn Indexes() -> Element {
     }
 }
 
-fn format_bytes(bytes: u64) -> String {
+pub(crate) fn format_bytes(bytes: u64) -> String {
     if bytes == 0 {
         return "0 MB".to_string();
     }
@@ -244,7 +419,7 @@ fn format_bytes(bytes: u64) -> String {
     }
 }
 
-fn format_utc(timestamp: i64) -> String {
+pub(crate) fn format_utc(timestamp: i64) -> String {
     Utc.from_utc_datetime(&NaiveDateTime::from_timestamp_opt(timestamp, 0).unwrap())
         .format("%Y/%m/%d %H:%M")
         .to_string()
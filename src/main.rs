@@ -1,14 +1,37 @@
 #![allow(non_snake_case)]
+mod auto_refresh;
+mod charts;
+mod cluster;
+mod connectivity;
+mod create_index;
+mod health;
 mod icons;
+mod index_actions;
+mod index_detail;
 mod indexes;
+mod metadata;
+mod metrics_dashboard;
+mod node_config;
+mod pipelines;
 mod query_editor;
 mod quickwit;
+mod request_inspector;
 
 use crate::quickwit::QuickwitApi;
+use cluster::ClusterOverview;
+use connectivity::{ConnectivityBanner, use_connectivity_watch, use_reconnect};
+use create_index::CreateIndex;
 use dioxus::prelude::*;
 use dioxus_logger::tracing::{Level, info};
+use health::{HealthBadge, use_health_monitor_provider};
+use index_detail::IndexDetail;
 use indexes::Indexes;
+use metadata::use_metadata_cache_provider;
+use metrics_dashboard::MetricsDashboard;
+use node_config::NodeConfigViewer;
+use pipelines::PipelineMonitor;
 use query_editor::QueryEditor;
+use request_inspector::{InspectorToggle, RequestInspector};
 
 #[derive(Clone, Routable, Debug, PartialEq)]
 enum Route {
@@ -20,6 +43,18 @@ enum Route {
     QueryEditor,
     #[route("/indexes")]
     Indexes,
+    #[route("/create-index")]
+    CreateIndex,
+    #[route("/indexes/:index_id")]
+    IndexDetail { index_id: String },
+    #[route("/cluster")]
+    ClusterOverview,
+    #[route("/cluster/config")]
+    NodeConfigViewer,
+    #[route("/metrics")]
+    MetricsDashboard,
+    #[route("/pipelines")]
+    PipelineMonitor,
 }
 
 fn main() {
@@ -84,13 +119,10 @@ fn Home() -> Element {
     rsx! {}
 }
 
-#[derive(serde::Deserialize, Debug)]
-struct Cluster {
-    cluster_id: String,
-}
-
 fn Header() -> Element {
-    let data = use_resource(|| async { QuickwitApi::cluster().await.unwrap() });
+    let mut data =
+        use_resource(|| async { QuickwitApi::cluster().await.map_err(|e| e.to_string()) });
+    use_reconnect(move |_| data.restart());
 
     rsx! {
         header {
@@ -102,10 +134,26 @@ fn Header() -> Element {
             padding: "0 16px",
             font_weight: "bold",
             justify_content: "space-between",
-            if let Some(data) = data.read().as_ref() {
-                p { "{data.cluster_id}" }
+            div { display: "flex", align_items: "center",
+                match &*data.read() {
+                    Some(Ok(data)) => rsx! {
+                        Link {
+                            to: Route::ClusterOverview {},
+                            style: "color: inherit; text-decoration: none;",
+                            "{data.cluster_id}"
+                        }
+                    },
+                    Some(Err(e)) => rsx! {
+                        span { color: "#e57373", font_weight: "400", font_size: "13px", title: "{e}",
+                            "Cluster unavailable"
+                        }
+                    },
+                    None => rsx! {},
+                }
+                HealthBadge {}
             }
-            div { display: "flex", gap: "25px", margin_right: "20px",
+            div { display: "flex", align_items: "center", gap: "25px", margin_right: "20px",
+                InspectorToggle {}
                 a {
                     href: "https://quickwit.io/docs",
                     text_decoration: "underline",
@@ -121,15 +169,21 @@ fn Header() -> Element {
 
 #[component]
 pub fn NavLayout() -> Element {
+    use_health_monitor_provider();
+    use_metadata_cache_provider();
+    use_connectivity_watch();
+
     rsx! {
         div {
             display: "flex",
             flex_direction: "column",
             class: "layout-container",
             Header {}
+            ConnectivityBanner {}
             div {
                 display: "flex",
                 flex: "1",
+                min_height: 0,
                 border_left: "1px solid #8c82731f",
                 nav { border_right: "1px solid #8c82731f", width: "180px",
                     div {
@@ -147,6 +201,27 @@ pub fn NavLayout() -> Element {
                             },
                             text: "Indexes",
                         }
+                        NavLink {
+                            to: Route::ClusterOverview {},
+                            icon: rsx! {
+                                icons::Server {}
+                            },
+                            text: "Cluster",
+                        }
+                        NavLink {
+                            to: Route::MetricsDashboard {},
+                            icon: rsx! {
+                                icons::Chart {}
+                            },
+                            text: "Metrics",
+                        }
+                        NavLink {
+                            to: Route::PipelineMonitor {},
+                            icon: rsx! {
+                                icons::Activity {}
+                            },
+                            text: "Pipelines",
+                        }
                     }
                 }
                 div {
@@ -158,6 +233,7 @@ pub fn NavLayout() -> Element {
                     Outlet::<Route> {}
                 }
             }
+            RequestInspector {}
         }
     }
 }
@@ -0,0 +1,108 @@
+use crate::connectivity::use_reconnect;
+use crate::quickwit::QuickwitApi;
+use crate::quickwit::index::{Index, matches_index_expr};
+use chrono::Utc;
+use dioxus::prelude::*;
+
+/// Seconds before cached metadata is fetched again on next use.
+const TTL_SECS: i64 = 30;
+
+/// Index metadata, doc mappings included, shared by every page so they read
+/// one copy instead of each fetching `/api/v1/indexes`.
+#[derive(Clone, Copy)]
+pub struct MetadataCache {
+    indexes: Signal<Option<Result<Vec<Index>, String>>>,
+    /// When the cached list was fetched, in seconds; `None` once invalidated.
+    fetched_at: Signal<Option<i64>>,
+    /// Bumped by every fetch so only the latest one lands.
+    generation: Signal<u64>,
+}
+
+impl MetadataCache {
+    /// Cached indexes, `None` until the first fetch completes.
+    pub fn indexes(&self) -> Option<Result<Vec<Index>, String>> {
+        self.indexes.read().clone()
+    }
+
+    pub fn index(&self, index_id: &str) -> Option<Index> {
+        self.indexes
+            .read()
+            .as_ref()?
+            .as_ref()
+            .ok()?
+            .iter()
+            .find(|index| index.index_config.index_id == index_id)
+            .cloned()
+    }
+
+    /// Timestamp field of the indexes matching `index_expr`, if they all
+    /// share the same one.
+    pub fn timestamp_field(&self, index_expr: &str) -> Option<String> {
+        let indexes = self.indexes.read();
+        let mut timestamp_fields = indexes
+            .as_ref()?
+            .as_ref()
+            .ok()?
+            .iter()
+            .filter(|index| matches_index_expr(index_expr, &index.index_config.index_id))
+            .map(|index| &index.index_config.doc_mapping.timestamp_field);
+        let first = timestamp_fields.next()?;
+        timestamp_fields
+            .all(|field| field == first)
+            .then(|| first.clone())
+    }
+
+    fn is_stale(&self) -> bool {
+        self.fetched_at
+            .peek()
+            .is_none_or(|fetched_at| Utc::now().timestamp() - fetched_at >= TTL_SECS)
+    }
+
+    /// Fetches the indexes unless the cached ones are still fresh.
+    pub fn refresh_if_stale(&self) {
+        if self.is_stale() {
+            self.refresh();
+        }
+    }
+
+    /// Fetches the indexes, keeping the cached ones visible meanwhile.
+    /// Concurrent callers share one request through `QuickwitApi`.
+    pub fn refresh(&self) {
+        let mut cache = *self;
+        cache.generation += 1;
+        let generation = *cache.generation.peek();
+        // Not tied to the caller, which may be a dialog about to close.
+        spawn_forever(async move {
+            let result = QuickwitApi::indexes().await.map_err(|e| e.to_string());
+            if *cache.generation.peek() == generation {
+                cache.indexes.set(Some(result));
+                cache.fetched_at.set(Some(Utc::now().timestamp()));
+            }
+        });
+    }
+
+    /// Marks the cache stale after a mutation and refetches.
+    pub fn invalidate(&self) {
+        let mut fetched_at = self.fetched_at;
+        fetched_at.set(None);
+        self.refresh();
+    }
+}
+
+/// Makes the metadata cache available to every component below the caller.
+pub fn use_metadata_cache_provider() -> MetadataCache {
+    let cache = use_context_provider(|| MetadataCache {
+        indexes: Signal::new(None),
+        fetched_at: Signal::new(None),
+        generation: Signal::new(0),
+    });
+    use_reconnect(move |_| cache.refresh());
+    cache
+}
+
+/// The shared cache, refreshed on mount when its contents are stale.
+pub fn use_metadata_cache() -> MetadataCache {
+    let cache: MetadataCache = use_context();
+    use_hook(|| cache.refresh_if_stale());
+    cache
+}
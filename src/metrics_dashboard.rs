@@ -0,0 +1,311 @@
+use crate::auto_refresh::{AutoRefreshSelect, RefreshInterval, use_auto_refresh};
+use crate::charts::{ChartSeries, LineChart, Unit};
+use crate::create_index::INPUT_STYLE;
+use crate::quickwit::QuickwitApi;
+use crate::quickwit::indexing::{PROCESSED_BYTES, PROCESSED_DOCS};
+use crate::quickwit::metrics::{self, Exposition, Histogram, MetricFamily, MetricKind, Sample};
+use chrono::Utc;
+use dioxus::prelude::*;
+use std::collections::{BTreeSet, VecDeque};
+
+const MAX_SNAPSHOTS: usize = 120;
+
+// Series charted by the dashboard, as named by Quickwit.
+const ROOT_SEARCH_DURATION: &str = "quickwit_search_root_search_request_duration_seconds";
+const LEAF_SEARCH_DURATION: &str = "quickwit_search_leaf_search_request_duration_seconds";
+const CACHE_HITS: &str = "quickwit_cache_hits_total";
+const CACHE_MISSES: &str = "quickwit_cache_misses_total";
+const ONGOING_MERGES: &str = "quickwit_indexing_ongoing_merge_operations";
+const PENDING_MERGES: &str = "quickwit_indexing_pending_merge_operations";
+
+const CHARTED: [&str; 8] = [
+    PROCESSED_DOCS,
+    PROCESSED_BYTES,
+    ROOT_SEARCH_DURATION,
+    LEAF_SEARCH_DURATION,
+    CACHE_HITS,
+    CACHE_MISSES,
+    ONGOING_MERGES,
+    PENDING_MERGES,
+];
+
+/// The charted samples of one scrape, taken at `at` (seconds).
+#[derive(Debug, Clone, PartialEq)]
+struct Snapshot {
+    at: f64,
+    samples: Vec<Sample>,
+}
+
+fn is_charted(sample: &Sample) -> bool {
+    CHARTED.iter().any(|name| {
+        sample
+            .name
+            .strip_prefix(name)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('_'))
+    })
+}
+
+/// Per-second increase of a counter between consecutive snapshots. Counter
+/// resets show up as zero rather than a negative rate.
+fn rate_series(
+    snapshots: &VecDeque<Snapshot>,
+    label: &str,
+    name: &str,
+    filter: impl Fn(&Sample) -> bool,
+) -> ChartSeries {
+    let points = snapshots
+        .iter()
+        .zip(snapshots.iter().skip(1))
+        .map(|(previous, current)| {
+            let delta = metrics::sum(&current.samples, name, &filter)
+                - metrics::sum(&previous.samples, name, &filter);
+            (current.at, (delta / (current.at - previous.at)).max(0.0))
+        })
+        .collect();
+    ChartSeries {
+        label: label.to_string(),
+        points,
+    }
+}
+
+fn gauge_series(snapshots: &VecDeque<Snapshot>, label: &str, name: &str) -> ChartSeries {
+    ChartSeries {
+        label: label.to_string(),
+        points: snapshots
+            .iter()
+            .map(|snapshot| (snapshot.at, metrics::sum(&snapshot.samples, name, |_| true)))
+            .collect(),
+    }
+}
+
+/// Latency quantile over each sampling interval. Intervals without requests
+/// leave a gap.
+fn quantile_series(snapshots: &VecDeque<Snapshot>, label: &str, name: &str, q: f64) -> ChartSeries {
+    let points =
+        snapshots
+            .iter()
+            .zip(snapshots.iter().skip(1))
+            .filter_map(|(previous, current)| {
+                let window = Histogram::new(&current.samples, name, |_| true)
+                    .since(&Histogram::new(&previous.samples, name, |_| true));
+                window.quantile(q).map(|value| (current.at, value))
+            })
+            .collect();
+    ChartSeries {
+        label: label.to_string(),
+        points,
+    }
+}
+
+fn cache_ratio_series(snapshots: &VecDeque<Snapshot>) -> Vec<ChartSeries> {
+    let components: BTreeSet<String> = snapshots
+        .back()
+        .into_iter()
+        .flat_map(|snapshot| snapshot.samples.iter())
+        .filter(|sample| sample.name == CACHE_HITS)
+        .filter_map(|sample| sample.label("component_name").map(str::to_string))
+        .collect();
+
+    components
+        .into_iter()
+        .map(|component| {
+            let for_component =
+                |sample: &Sample| sample.label("component_name") == Some(component.as_str());
+            let points = snapshots
+                .iter()
+                .zip(snapshots.iter().skip(1))
+                .filter_map(|(previous, current)| {
+                    let hits = metrics::sum(&current.samples, CACHE_HITS, for_component)
+                        - metrics::sum(&previous.samples, CACHE_HITS, for_component);
+                    let misses = metrics::sum(&current.samples, CACHE_MISSES, for_component)
+                        - metrics::sum(&previous.samples, CACHE_MISSES, for_component);
+                    (hits + misses > 0.0).then(|| (current.at, hits / (hits + misses)))
+                })
+                .collect();
+            ChartSeries {
+                label: component.clone(),
+                points,
+            }
+        })
+        .collect()
+}
+
+#[component]
+fn MetricFamilies(exposition: Exposition) -> Element {
+    let mut filter = use_signal(String::new);
+    let needle = filter().to_lowercase();
+    let rows: Vec<(MetricFamily, usize, String)> = exposition
+        .families
+        .values()
+        .filter(|family| {
+            family.name.contains(&needle) || family.help.to_lowercase().contains(&needle)
+        })
+        .map(|family| {
+            let samples: Vec<&Sample> = exposition
+                .samples
+                .iter()
+                .filter(|sample| family.contains(sample))
+                .collect();
+            let value = match family.kind {
+                // Bucket counts are cumulative, so only `_count` is summed.
+                MetricKind::Histogram | MetricKind::Summary => {
+                    let count = format!("{}_count", family.name);
+                    let observations: f64 = samples
+                        .iter()
+                        .filter(|sample| sample.name == count)
+                        .map(|sample| sample.value)
+                        .sum();
+                    format!("{observations} observations")
+                }
+                _ => format!("{}", samples.iter().map(|sample| sample.value).sum::<f64>()),
+            };
+            (family.clone(), samples.len(), value)
+        })
+        .collect();
+
+    rsx! {
+        div { display: "flex", align_items: "center", justify_content: "space-between", margin: "24px 0 8px",
+            h2 { style: "font-size: 13px; font-weight: 600; color: #f8f9fa;",
+                "All metrics ({exposition.families.len()})"
+            }
+            input {
+                style: "{INPUT_STYLE} width: 300px;",
+                placeholder: "Filter by name or description",
+                value: "{filter}",
+                oninput: move |e| filter.set(e.value()),
+            }
+        }
+        table { style: "width: 100%; border-collapse: collapse; background: #181a1b; font-size: 12px;",
+            thead {
+                tr { style: "border-bottom: 1px solid #404040;",
+                    for name in ["Name", "Type", "Series", "Value", "Description"] {
+                        th {
+                            key: "{name}",
+                            style: "padding: 8px 12px; text-align: left; color: #f8f9fa;",
+                            "{name}"
+                        }
+                    }
+                }
+            }
+            tbody {
+                for (family , num_series , value) in rows {
+                    tr { key: "{family.name}", style: "border-bottom: 1px solid #2a2a2a;",
+                        td { style: "padding: 6px 12px; color: #f8f9fa; font-family: monospace;",
+                            "{family.name}"
+                        }
+                        td { style: "padding: 6px 12px; color: #b0b0b0;", "{family.kind.as_str()}" }
+                        td { style: "padding: 6px 12px; color: #b0b0b0;", "{num_series}" }
+                        td { style: "padding: 6px 12px; color: #b0b0b0;", "{value}" }
+                        td { style: "padding: 6px 12px; color: #b0b0b0;", "{family.help}" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Charts key series from `/metrics`, sampled by the browser while the page
+/// is open. History is lost when leaving the page.
+#[component]
+pub fn MetricsDashboard() -> Element {
+    let mut snapshots: Signal<VecDeque<Snapshot>> = use_signal(VecDeque::new);
+    let mut latest: Signal<Option<Exposition>> = use_signal(|| None);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    let refresh_interval = use_signal(|| RefreshInterval::FiveSeconds);
+
+    let sample = use_callback(move |_: ()| {
+        spawn(async move {
+            match QuickwitApi::metrics_exposition().await {
+                Ok(exposition) => {
+                    let at = Utc::now().timestamp_millis() as f64 / 1000.0;
+                    let samples = exposition
+                        .samples
+                        .iter()
+                        .filter(|sample| is_charted(sample))
+                        .cloned()
+                        .collect();
+                    let mut snapshots = snapshots.write();
+                    snapshots.push_back(Snapshot { at, samples });
+                    while snapshots.len() > MAX_SNAPSHOTS {
+                        snapshots.pop_front();
+                    }
+                    latest.set(Some(exposition));
+                    error.set(None);
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    });
+    use_hook(|| sample.call(()));
+    use_auto_refresh(refresh_interval, move |_| sample.call(()));
+
+    let history = snapshots.read();
+    let is_valid = |sample: &Sample| sample.label("docs_processed_status") == Some("valid");
+
+    rsx! {
+        div { height: "100%", padding: "20px", overflow_y: "auto",
+            div {
+                display: "flex",
+                align_items: "center",
+                justify_content: "space-between",
+                margin_bottom: "16px",
+                h1 { style: "font-size: 14px; font-weight: 700; color: #f8f9fa;", "Metrics" }
+                div { display: "flex", align_items: "center", gap: "12px",
+                    span { color: "#5F6060", font_size: "13px", "{history.len()} samples" }
+                    AutoRefreshSelect { interval: refresh_interval }
+                }
+            }
+            if let Some(e) = error() {
+                p { style: "color: #e57373; font-size: 13px; margin-bottom: 12px;", "{e}" }
+            }
+            div { display: "flex", flex_wrap: "wrap", gap: "12px",
+                LineChart {
+                    title: "Indexing throughput (docs)",
+                    unit: Unit::PerSecond,
+                    series: vec![
+                        rate_series(&history, "all", PROCESSED_DOCS, |_| true),
+                        rate_series(&history, "valid", PROCESSED_DOCS, is_valid),
+                    ],
+                }
+                LineChart {
+                    title: "Indexing throughput (bytes)",
+                    unit: Unit::BytesPerSecond,
+                    series: vec![rate_series(&history, "processed", PROCESSED_BYTES, |_| true)],
+                }
+                LineChart {
+                    title: "Root search latency",
+                    unit: Unit::Seconds,
+                    series: vec![
+                        quantile_series(&history, "p50", ROOT_SEARCH_DURATION, 0.5),
+                        quantile_series(&history, "p90", ROOT_SEARCH_DURATION, 0.9),
+                        quantile_series(&history, "p99", ROOT_SEARCH_DURATION, 0.99),
+                    ],
+                }
+                LineChart {
+                    title: "Leaf search latency",
+                    unit: Unit::Seconds,
+                    series: vec![
+                        quantile_series(&history, "p50", LEAF_SEARCH_DURATION, 0.5),
+                        quantile_series(&history, "p99", LEAF_SEARCH_DURATION, 0.99),
+                    ],
+                }
+                LineChart {
+                    title: "Cache hit ratio",
+                    unit: Unit::Ratio,
+                    series: cache_ratio_series(&history),
+                }
+                LineChart {
+                    title: "Merge operations",
+                    unit: Unit::Count,
+                    series: vec![
+                        gauge_series(&history, "ongoing", ONGOING_MERGES),
+                        gauge_series(&history, "pending", PENDING_MERGES),
+                    ],
+                }
+            }
+            if let Some(exposition) = latest() {
+                MetricFamilies { exposition }
+            }
+        }
+    }
+}
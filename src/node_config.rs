@@ -0,0 +1,314 @@
+use crate::create_index::{Checkbox, INPUT_STYLE};
+use crate::quickwit::QuickwitApi;
+use crate::quickwit::config::{REDACTED, redact};
+use dioxus::prelude::*;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+
+fn format_leaf(value: &Value) -> String {
+    match value {
+        Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
+
+/// Leaf values keyed by their dotted path.
+fn flatten(prefix: &str, value: &Value, leaves: &mut BTreeMap<String, String>) {
+    let child_path = |key: &str| {
+        if prefix.is_empty() {
+            key.to_string()
+        } else {
+            format!("{prefix}.{key}")
+        }
+    };
+    match value {
+        Value::Object(object) if !object.is_empty() => {
+            for (key, value) in object {
+                flatten(&child_path(key), value, leaves);
+            }
+        }
+        Value::Array(values) if !values.is_empty() => {
+            for (i, value) in values.iter().enumerate() {
+                flatten(&child_path(&i.to_string()), value, leaves);
+            }
+        }
+        value => {
+            leaves.insert(prefix.to_string(), format_leaf(value));
+        }
+    }
+}
+
+fn matches(path: &str, value: &Value, needle: &str) -> bool {
+    if needle.is_empty() || path.to_lowercase().contains(needle) {
+        return true;
+    }
+    match value {
+        Value::Object(object) => object
+            .iter()
+            .any(|(key, value)| matches(&format!("{path}.{key}"), value, needle)),
+        Value::Array(values) => values
+            .iter()
+            .enumerate()
+            .any(|(i, value)| matches(&format!("{path}.{i}"), value, needle)),
+        value => format_leaf(value).to_lowercase().contains(needle),
+    }
+}
+
+#[component]
+fn ConfigTree(name: String, path: String, value: Value, needle: String) -> Element {
+    let children: Vec<(String, Value)> = match &value {
+        Value::Object(object) => object
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect(),
+        Value::Array(values) => values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| (i.to_string(), value.clone()))
+            .collect(),
+        _ => Vec::new(),
+    };
+    let child_path = |key: &str| {
+        if path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{path}.{key}")
+        }
+    };
+
+    rsx! {
+        if children.is_empty() {
+            div { style: "padding: 2px 0 2px 16px; font-size: 12px; font-family: monospace;",
+                span { color: "#b0b0b0", "{name}: " }
+                span { color: if format_leaf(&value) == REDACTED { "#ffb74d" } else { "#f8f9fa" },
+                    "{format_leaf(&value)}"
+                }
+            }
+        } else {
+            details {
+                open: !needle.is_empty() || path.is_empty(),
+                style: "padding-left: 12px; font-size: 12px; font-family: monospace;",
+                summary { style: "cursor: pointer; color: #f8f9fa; padding: 2px 0;", "{name}" }
+                for (key , child) in children
+                    .into_iter()
+                    .filter(|(key, child)| matches(&child_path(key), child, &needle))
+                {
+                    ConfigTree {
+                        key: "{key}",
+                        name: key.clone(),
+                        path: child_path(&key),
+                        value: child,
+                        needle: needle.clone(),
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn row_background(differs: bool) -> &'static str {
+    if differs {
+        "rgba(255, 183, 77, 0.08)"
+    } else {
+        "transparent"
+    }
+}
+
+#[component]
+fn ConfigComparison(
+    configs: Vec<(String, Value)>,
+    needle: String,
+    only_differences: bool,
+) -> Element {
+    let leaves: Vec<BTreeMap<String, String>> = configs
+        .iter()
+        .map(|(_, config)| {
+            let mut leaves = BTreeMap::new();
+            flatten("", config, &mut leaves);
+            leaves
+        })
+        .collect();
+    let paths: BTreeSet<&String> = leaves.iter().flat_map(|leaves| leaves.keys()).collect();
+    let rows: Vec<(String, Vec<Option<String>>, bool)> = paths
+        .into_iter()
+        .map(|path| {
+            let values: Vec<Option<String>> = leaves
+                .iter()
+                .map(|leaves| leaves.get(path).cloned())
+                .collect();
+            let differs = values.iter().any(|value| *value != values[0]);
+            (path.clone(), values, differs)
+        })
+        .filter(|(path, values, differs)| {
+            (!only_differences || *differs)
+                && (needle.is_empty()
+                    || path.to_lowercase().contains(&needle)
+                    || values
+                        .iter()
+                        .flatten()
+                        .any(|value| value.to_lowercase().contains(&needle)))
+        })
+        .collect();
+
+    rsx! {
+        table { style: "width: 100%; border-collapse: collapse; background: #181a1b; font-size: 12px; font-family: monospace;",
+            thead {
+                tr { style: "border-bottom: 1px solid #404040;",
+                    th { style: "padding: 6px 12px; text-align: left; color: #f8f9fa;", "Setting" }
+                    for (node_id , _) in configs.iter() {
+                        th {
+                            key: "{node_id}",
+                            style: "padding: 6px 12px; text-align: left; color: #f8f9fa;",
+                            "{node_id}"
+                        }
+                    }
+                }
+            }
+            tbody {
+                for (path , values , differs) in rows {
+                    tr {
+                        key: "{path}",
+                        style: "border-bottom: 1px solid #2a2a2a; background: {row_background(differs)};",
+                        td { style: "padding: 4px 12px; color: #b0b0b0; word-break: break-all;", "{path}" }
+                        for (i , value) in values.into_iter().enumerate() {
+                            td {
+                                key: "{i}",
+                                style: "padding: 4px 12px; color: #f8f9fa; word-break: break-all;",
+                                {value.as_deref().unwrap_or("—")}
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Effective configuration of each node, redacted, as a tree for one node or
+/// side by side when several are selected.
+#[component]
+pub fn NodeConfigViewer() -> Element {
+    let mut selected: Signal<Vec<String>> = use_signal(Vec::new);
+    let mut search = use_signal(String::new);
+    let mut only_differences = use_signal(|| false);
+
+    let configs = use_resource(|| async {
+        let cluster = QuickwitApi::cluster().await?;
+        // Fetched concurrently so a slow node does not hold up the others.
+        let fetches = cluster.nodes().into_iter().map(|node| {
+            let node_url = QuickwitApi::node_url(&node, &cluster.self_node_id);
+            async move {
+                let config = QuickwitApi::node_config(&node_url)
+                    .await
+                    .map(|config| redact(&config))
+                    .map_err(|e| e.to_string());
+                (node.chitchat_id.node_id, config)
+            }
+        });
+        anyhow::Ok(futures::future::join_all(fetches).await)
+    });
+
+    let needle = search().to_lowercase();
+
+    rsx! {
+        div { height: "100%", padding: "20px", overflow_y: "auto",
+            h1 { style: "font-size: 14px; font-weight: 700; color: #f8f9fa; margin-bottom: 16px;",
+                "Node configuration"
+            }
+            match &*configs.read() {
+                Some(Ok(configs)) => {
+                    // Default to the first node that answered.
+                    let shown: Vec<(String, Value)> = configs
+                        .iter()
+                        .filter_map(|(node_id, config)| Some((node_id.clone(), config.as_ref().ok()?.clone())))
+                        .filter(|(node_id, _)| selected.read().contains(node_id))
+                        .collect();
+                    let shown = if shown.is_empty() {
+                        configs
+                            .iter()
+                            .find_map(|(node_id, config)| Some((node_id.clone(), config.as_ref().ok()?.clone())))
+                            .into_iter()
+                            .collect()
+                    } else {
+                        shown
+                    };
+                    rsx! {
+                        div { display: "flex", flex_wrap: "wrap", align_items: "center", gap: "16px", margin_bottom: "12px",
+                            for (node_id , config) in configs.iter() {
+                                match config {
+                                    Ok(_) => rsx! {
+                                        Checkbox {
+                                            key: "{node_id}",
+                                            label: node_id.clone(),
+                                            checked: shown.iter().any(|(shown_id, _)| shown_id == node_id),
+                                            onchange: {
+                                                let node_id = node_id.clone();
+                                                let shown_ids: Vec<String> = shown.iter().map(|(id, _)| id.clone()).collect();
+                                                move |checked: bool| {
+                                                    let mut ids = shown_ids.clone();
+                                                    ids.retain(|id| *id != node_id);
+                                                    if checked {
+                                                        ids.push(node_id.clone());
+                                                    }
+                                                    selected.set(ids);
+                                                }
+                                            },
+                                        }
+                                    },
+                                    Err(e) => rsx! {
+                                        span {
+                                            key: "{node_id}",
+                                            color: "#e57373",
+                                            font_size: "13px",
+                                            title: "{e}",
+                                            "{node_id}: unavailable"
+                                        }
+                                    },
+                                }
+                            }
+                        }
+                        div { display: "flex", align_items: "center", gap: "16px", margin_bottom: "12px",
+                            input {
+                                style: "{INPUT_STYLE} width: 300px;",
+                                placeholder: "Search settings and values",
+                                value: "{search}",
+                                oninput: move |e| search.set(e.value()),
+                            }
+                            if shown.len() > 1 {
+                                Checkbox {
+                                    label: "only differences",
+                                    checked: only_differences(),
+                                    onchange: move |checked| only_differences.set(checked),
+                                }
+                            }
+                        }
+                        match shown.len() {
+                            0 => rsx! {
+                                p { style: "color: #b0b0b0; font-size: 13px;", "No node returned its configuration." }
+                            },
+                            1 => rsx! {
+                                div { style: "background: #181a1b; border: 1px solid #404040; border-radius: 4px; padding: 8px;",
+                                    ConfigTree {
+                                        name: shown[0].0.clone(),
+                                        path: "",
+                                        value: shown[0].1.clone(),
+                                        needle: needle.clone(),
+                                    }
+                                }
+                            },
+                            _ => rsx! {
+                                ConfigComparison { configs: shown.clone(), needle: needle.clone(), only_differences: only_differences() }
+                            },
+                        }
+                    }
+                }
+                Some(Err(e)) => rsx! {
+                    p { style: "color: #e57373; font-size: 13px;", "{e}" }
+                },
+                None => rsx! {
+                    p { style: "color: #b0b0b0; font-size: 13px;", "Loading..." }
+                },
+            }
+        }
+    }
+}
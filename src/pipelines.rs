@@ -0,0 +1,341 @@
+use crate::auto_refresh::{AutoRefreshSelect, RefreshInterval, use_auto_refresh};
+use crate::charts::Sparkline;
+use crate::indexes::format_bytes;
+use crate::quickwit::QuickwitApi;
+use crate::quickwit::cluster::IndexingPipeline;
+use crate::quickwit::indexing::{
+    IndexIndexingStats, IndexingServiceCounters, PROCESSED_BYTES, PROCESSED_DOCS,
+};
+use crate::quickwit::metrics::Sample;
+use chrono::Utc;
+use dioxus::prelude::*;
+use std::collections::VecDeque;
+
+const MAX_POLLS: usize = 30;
+/// Polls without any processed document before a pipeline is flagged idle.
+const IDLE_POLLS: usize = 3;
+
+#[derive(Debug, Clone, PartialEq)]
+struct NodePoll {
+    node_id: String,
+    /// Indexing samples from the node's `/metrics`, or why they are missing.
+    samples: Result<Vec<Sample>, String>,
+    counters: Option<IndexingServiceCounters>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Poll {
+    /// Seconds.
+    at: f64,
+    pipelines: Vec<IndexingPipeline>,
+    nodes: Vec<NodePoll>,
+}
+
+/// Pipelines of one index on one node. Quickwit labels its indexing
+/// counters by index only, so they are only known per group.
+#[derive(Debug, Clone, PartialEq)]
+struct PipelineGroup {
+    node_id: String,
+    index_id: String,
+    pipelines: Vec<IndexingPipeline>,
+}
+
+fn group_pipelines(mut pipelines: Vec<IndexingPipeline>) -> Vec<PipelineGroup> {
+    pipelines.sort_by(|a, b| {
+        (&a.index_id, &a.node_id, &a.source_id, a.ordinal).cmp(&(
+            &b.index_id,
+            &b.node_id,
+            &b.source_id,
+            b.ordinal,
+        ))
+    });
+    let mut groups: Vec<PipelineGroup> = Vec::new();
+    for pipeline in pipelines {
+        match groups.last_mut() {
+            Some(group)
+                if group.node_id == pipeline.node_id && group.index_id == pipeline.index_id =>
+            {
+                group.pipelines.push(pipeline);
+            }
+            _ => groups.push(PipelineGroup {
+                node_id: pipeline.node_id.clone(),
+                index_id: pipeline.index_id.clone(),
+                pipelines: vec![pipeline],
+            }),
+        }
+    }
+    groups
+}
+
+impl Poll {
+    /// Counters of an index on one node, summed over its pipelines.
+    fn stats(&self, node_id: &str, index_id: &str) -> Option<IndexIndexingStats> {
+        let node = self.nodes.iter().find(|node| node.node_id == node_id)?;
+        let samples = node.samples.as_ref().ok()?;
+        Some(IndexIndexingStats::new(index_id, samples, Vec::new()))
+    }
+}
+
+async fn poll() -> anyhow::Result<Poll> {
+    let cluster = QuickwitApi::cluster().await?;
+    let pipelines = cluster.indexing_pipelines();
+
+    // Polled concurrently so a slow or unreachable indexer does not hold up
+    // the others.
+    let polls = cluster
+        .nodes()
+        .into_iter()
+        .filter(|node| {
+            node.roles.iter().any(|role| role == "indexer")
+                || pipelines
+                    .iter()
+                    .any(|pipeline| pipeline.node_id == node.chitchat_id.node_id)
+        })
+        .map(|node| {
+            let node_url = QuickwitApi::node_url(&node, &cluster.self_node_id);
+            async move {
+                let (samples, counters) = futures::join!(
+                    QuickwitApi::node_metrics(&node_url),
+                    QuickwitApi::node_indexing_counters(&node_url),
+                );
+                let samples = samples
+                    .map(|samples| {
+                        samples
+                            .into_iter()
+                            .filter(|sample| {
+                                sample.name == PROCESSED_DOCS || sample.name == PROCESSED_BYTES
+                            })
+                            .collect()
+                    })
+                    .map_err(|e| e.to_string());
+                NodePoll {
+                    node_id: node.chitchat_id.node_id,
+                    samples,
+                    counters: counters.ok(),
+                }
+            }
+        });
+    let nodes = futures::future::join_all(polls).await;
+
+    Ok(Poll {
+        at: Utc::now().timestamp_millis() as f64 / 1000.0,
+        pipelines,
+        nodes,
+    })
+}
+
+/// Documents and bytes per second between consecutive polls.
+fn throughput(polls: &VecDeque<Poll>, node_id: &str, index_id: &str) -> Vec<(f64, f64)> {
+    polls
+        .iter()
+        .zip(polls.iter().skip(1))
+        .filter_map(|(previous, current)| {
+            let before = previous.stats(node_id, index_id)?;
+            let after = current.stats(node_id, index_id)?;
+            let elapsed = current.at - previous.at;
+            Some((
+                (after
+                    .num_docs_processed()
+                    .saturating_sub(before.num_docs_processed())) as f64
+                    / elapsed,
+                (after
+                    .total_bytes_processed
+                    .saturating_sub(before.total_bytes_processed)) as f64
+                    / elapsed,
+            ))
+        })
+        .collect()
+}
+
+/// Problems of the node as a whole; Quickwit does not say which pipeline
+/// failed.
+fn node_error(node: Option<&NodePoll>) -> Option<String> {
+    let node = node?;
+    match (&node.samples, &node.counters) {
+        (Err(e), _) => Some(format!("metrics unavailable: {e}")),
+        (_, Some(counters)) if counters.num_failed_pipelines > 0 => Some(format!(
+            "{} failed pipelines on node",
+            counters.num_failed_pipelines
+        )),
+        _ => None,
+    }
+}
+
+const CELL: &str = "padding: 8px 12px; color: #b0b0b0;";
+
+#[component]
+fn PipelineGroupRow(group: PipelineGroup, polls: Signal<VecDeque<Poll>>) -> Element {
+    let polls = polls.read();
+    let Some(latest) = polls.back() else {
+        return rsx! {};
+    };
+    let stats = latest.stats(&group.node_id, &group.index_id);
+    let rates = throughput(&polls, &group.node_id, &group.index_id);
+    let docs_rate: Vec<f64> = rates.iter().map(|(docs, _)| *docs).collect();
+    let is_idle = docs_rate.len() >= IDLE_POLLS
+        && docs_rate
+            .iter()
+            .rev()
+            .take(IDLE_POLLS)
+            .all(|rate| *rate == 0.0);
+    let row_background = if is_idle {
+        "rgba(255, 183, 77, 0.08)"
+    } else {
+        "transparent"
+    };
+    let error = node_error(
+        latest
+            .nodes
+            .iter()
+            .find(|node| node.node_id == group.node_id),
+    );
+
+    rsx! {
+        tr {
+            style: "border-bottom: 1px solid #2a2a2a; background: {row_background};",
+            td { style: "padding: 8px 12px; color: #f8f9fa;", "{group.index_id}" }
+            td { style: CELL, "{group.node_id}" }
+            td { style: CELL,
+                for pipeline in &group.pipelines {
+                    div { key: "{pipeline.source_id}/{pipeline.ordinal}",
+                        "{pipeline.source_id} #{pipeline.ordinal}"
+                        span { color: "#5F6060", font_size: "11px",
+                            " gen {pipeline.node_generation}"
+                            if let Some(pipeline_uid) = &pipeline.pipeline_uid {
+                                " {pipeline_uid}"
+                            }
+                        }
+                    }
+                }
+            }
+            match &stats {
+                Some(stats) => rsx! {
+                    td { style: CELL, "{stats.num_docs_processed()}" }
+                    td { style: CELL, "{stats.num_valid_docs}" }
+                    td {
+                        style: if stats.num_invalid_docs > 0 { "padding: 8px 12px; color: #e57373;" } else { CELL },
+                        "{stats.num_invalid_docs}"
+                    }
+                    td { style: CELL, "{format_bytes(stats.total_bytes_processed)}" }
+                },
+                None => rsx! {
+                    for column in 0..4 {
+                        td { key: "{column}", style: CELL, "—" }
+                    }
+                },
+            }
+            td { style: CELL,
+                match rates.last() {
+                    Some((docs, bytes)) => rsx! {
+                        "{docs:.1} docs/s · {format_bytes(*bytes as u64)}/s"
+                    },
+                    None => rsx! { "—" },
+                }
+                if is_idle {
+                    span { color: "#ffb74d", " idle" }
+                }
+            }
+            td { style: CELL,
+                Sparkline { values: docs_rate }
+            }
+            td { style: "padding: 8px 12px; color: #e57373; font-size: 12px;",
+                {error.as_deref().unwrap_or_default()}
+            }
+        }
+    }
+}
+
+/// Every indexing pipeline in the cluster, grouped by index and node, with
+/// throughput sampled from each indexer's `/metrics` while the page is open.
+#[component]
+pub fn PipelineMonitor() -> Element {
+    let mut polls: Signal<VecDeque<Poll>> = use_signal(VecDeque::new);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    let refresh_interval = use_signal(|| RefreshInterval::FiveSeconds);
+
+    let refresh = use_callback(move |_: ()| {
+        spawn(async move {
+            match poll().await {
+                Ok(poll) => {
+                    let mut polls = polls.write();
+                    polls.push_back(poll);
+                    while polls.len() > MAX_POLLS {
+                        polls.pop_front();
+                    }
+                    error.set(None);
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    });
+    use_hook(|| refresh.call(()));
+    use_auto_refresh(refresh_interval, move |_| refresh.call(()));
+
+    let groups = group_pipelines(
+        polls
+            .read()
+            .back()
+            .map(|poll| poll.pipelines.clone())
+            .unwrap_or_default(),
+    );
+
+    rsx! {
+        div { height: "100%", padding: "20px", overflow_y: "auto",
+            div {
+                display: "flex",
+                align_items: "center",
+                justify_content: "space-between",
+                margin_bottom: "16px",
+                h1 { style: "font-size: 14px; font-weight: 700; color: #f8f9fa;",
+                    "Indexing pipelines"
+                }
+                AutoRefreshSelect { interval: refresh_interval }
+            }
+            if let Some(e) = error() {
+                p { style: "color: #e57373; font-size: 13px; margin-bottom: 12px;", "{e}" }
+            }
+            p { style: "color: #5F6060; font-size: 12px; margin-bottom: 12px;",
+                "Quickwit reports indexing counters per index on each indexer, not per pipeline, so each row sums the pipelines of an index on one node. Failures are likewise only known per node."
+            }
+            table { style: "width: 100%; border-collapse: collapse; background: #181a1b; font-size: 13px;",
+                thead {
+                    tr { style: "border-bottom: 1px solid #404040;",
+                        for name in [
+                            "Index",
+                            "Node",
+                            "Pipelines",
+                            "Docs",
+                            "Valid",
+                            "Invalid",
+                            "Bytes",
+                            "Throughput",
+                            "Trend",
+                            "Node errors",
+                        ]
+                        {
+                            th {
+                                key: "{name}",
+                                style: "padding: 8px 12px; text-align: left; color: #f8f9fa; font-weight: 600;",
+                                "{name}"
+                            }
+                        }
+                    }
+                }
+                tbody {
+                    if polls.read().is_empty() {
+                        tr {
+                            td { colspan: "10", style: CELL, "Loading..." }
+                        }
+                    } else if groups.is_empty() {
+                        tr {
+                            td { colspan: "10", style: CELL, "No indexing pipelines are running." }
+                        }
+                    }
+                    for group in groups {
+                        PipelineGroupRow { key: "{group.node_id}/{group.index_id}", group, polls }
+                    }
+                }
+            }
+        }
+    }
+}
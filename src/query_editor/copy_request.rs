@@ -0,0 +1,165 @@
+use super::time_range_select::DateRange;
+use super::{QueryLanguage, SINGLE_INDEX_ONLY, search_request};
+use crate::document::eval;
+use crate::metadata::use_metadata_cache;
+use crate::quickwit::QuickwitApi;
+use crate::quickwit::elastic::ElasticRequest;
+use crate::quickwit::index::is_multi_index;
+use dioxus::prelude::*;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RequestFormat {
+    Curl,
+    QuickwitCli,
+    Elasticsearch,
+}
+
+impl fmt::Display for RequestFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RequestFormat::Curl => write!(f, "Copy as curl"),
+            RequestFormat::QuickwitCli => write!(f, "Copy as Quickwit CLI"),
+            RequestFormat::Elasticsearch => write!(f, "Copy as Elasticsearch DSL"),
+        }
+    }
+}
+
+impl RequestFormat {
+    const ALL: [RequestFormat; 3] = [
+        RequestFormat::Curl,
+        RequestFormat::QuickwitCli,
+        RequestFormat::Elasticsearch,
+    ];
+    /// The Quickwit CLI has no way to run an Elasticsearch DSL search.
+    const DSL: [RequestFormat; 2] = [RequestFormat::Curl, RequestFormat::Elasticsearch];
+}
+
+pub fn copy_to_clipboard(text: String) {
+    spawn(async move {
+        let script = eval(
+            r#"
+            const text = await dioxus.recv();
+            await navigator.clipboard.writeText(text);
+            "#,
+        );
+        let _ = script.send(text);
+        let _ = script.await;
+    });
+}
+
+#[component]
+pub fn CopyRequestMenu(
+    query: Signal<String>,
+    selected_index: Signal<Option<String>>,
+    max_hits: Signal<usize>,
+    date_range: Signal<Option<DateRange>>,
+    query_language: Signal<QueryLanguage>,
+    dsl: Signal<String>,
+) -> Element {
+    let mut is_open = use_signal(|| false);
+    let mut copied: Signal<Option<RequestFormat>> = use_signal(|| None);
+
+    let metadata = use_metadata_cache();
+
+    let render = move |format: RequestFormat| -> Option<String> {
+        let index_id = selected_index()?;
+        // In DSL mode the editor's DSL is what produced the results.
+        if query_language() == QueryLanguage::ElasticDsl {
+            return match format {
+                RequestFormat::Curl => ElasticRequest::parse(&dsl())
+                    .ok()
+                    .map(|request| QuickwitApi::elastic_curl(&index_id, &request)),
+                RequestFormat::QuickwitCli => None,
+                RequestFormat::Elasticsearch => Some(dsl()),
+            };
+        }
+        let timestamp_field = metadata.timestamp_field(&index_id);
+        let request = search_request(
+            &index_id,
+            timestamp_field.as_deref(),
+            &query(),
+            max_hits(),
+            date_range(),
+        );
+        Some(match format {
+            RequestFormat::Curl => request.to_curl(),
+            RequestFormat::QuickwitCli => request.to_cli(),
+            RequestFormat::Elasticsearch => {
+                let body = request.to_elasticsearch(timestamp_field.as_deref());
+                serde_json::to_string_pretty(&body).unwrap_or_default()
+            }
+        })
+    };
+
+    let is_dsl = query_language() == QueryLanguage::ElasticDsl;
+    // `_elastic` takes several indexes; the query string rendering does not.
+    let multi_index = !is_dsl && selected_index().is_some_and(|index_id| is_multi_index(&index_id));
+    let disabled = selected_index.read().is_none() || multi_index;
+    let formats: &[RequestFormat] = if is_dsl {
+        &RequestFormat::DSL
+    } else {
+        &RequestFormat::ALL
+    };
+
+    rsx! {
+        div { position: "relative", display: "inline-block", margin_right: "30px",
+            button {
+                r#type: "button",
+                border: "0",
+                border_radius: "4px",
+                padding: "6px 16px",
+                letter_spacing: "0.02857em",
+                color: if disabled { "#4C4D4D" } else { "white" },
+                background_color: if disabled { "#151718" } else { "black" },
+                cursor: if disabled { "not-allowed" } else { "pointer" },
+                disabled,
+                title: if multi_index { SINGLE_INDEX_ONLY } else { "" },
+                onclick: move |_| {
+                    copied.set(None);
+                    is_open.toggle();
+                },
+                "COPY REQUEST"
+            }
+
+            if is_open() {
+                ul {
+                    position: "absolute",
+                    top: "100%",
+                    right: 0,
+                    z_index: 1300,
+                    min_width: "240px",
+                    list_style: "none",
+                    padding: "4px 0",
+                    background_color: "#1e1e1e",
+                    border: "1px solid #404040",
+                    border_radius: "4px",
+                    box_shadow: "0 4px 6px rgba(0, 0, 0, 0.3)",
+                    for &format in formats {
+                        li { key: "{format}",
+                            button {
+                                width: "100%",
+                                padding: "8px 16px",
+                                border: "none",
+                                background: "none",
+                                cursor: "pointer",
+                                text_align: "left",
+                                onclick: move |_| {
+                                    if let Some(text) = render(format) {
+                                        copy_to_clipboard(text);
+                                        copied.set(Some(format));
+                                    }
+                                },
+                                if copied() == Some(format) {
+                                    "Copied!"
+                                } else {
+                                    "{format}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use super::QueryLanguage;
+use super::time_range_select::DateRange;
+use crate::index_detail::CreateDeleteTaskDialog;
+use crate::quickwit::index::is_multi_index;
+use dioxus::prelude::*;
+
+/// Opens a delete task for the documents matching the current query and time
+/// range. Only query string searches against a single index can be deleted.
+#[component]
+pub fn DeleteMatchingButton(
+    query: Signal<String>,
+    selected_index: Signal<Option<String>>,
+    date_range: Signal<Option<DateRange>>,
+    query_language: Signal<QueryLanguage>,
+) -> Element {
+    let mut is_open = use_signal(|| false);
+
+    let index_id = selected_index().filter(|index_id| !is_multi_index(index_id));
+    let disabled = index_id.is_none()
+        || query().trim().is_empty()
+        || query_language() != QueryLanguage::QueryString;
+    let (start_timestamp, end_timestamp) = match date_range() {
+        Some(date_range) => {
+            let (start, end) = date_range.bounds_secs();
+            (Some(start), end)
+        }
+        None => (None, None),
+    };
+
+    rsx! {
+        button {
+            r#type: "button",
+            border: "0",
+            border_radius: "4px",
+            padding: "6px 16px",
+            letter_spacing: "0.02857em",
+            color: if disabled { "#4C4D4D" } else { "white" },
+            background_color: if disabled { "#151718" } else { "#c62828" },
+            cursor: if disabled { "not-allowed" } else { "pointer" },
+            disabled,
+            onclick: move |_| is_open.set(true),
+            "DELETE MATCHING"
+        }
+        if let Some(index_id) = index_id.filter(|_| is_open()) {
+            CreateDeleteTaskDialog {
+                index_id,
+                query: query(),
+                start_timestamp,
+                end_timestamp,
+                on_close: move |_| is_open.set(false),
+                on_created: |_| {},
+            }
+        }
+    }
+}
@@ -0,0 +1,510 @@
+use super::time_range_select::DateRange;
+use super::{QueryLanguage, search_request};
+use crate::metadata::use_metadata_cache;
+use crate::quickwit::index::is_multi_index;
+use crate::quickwit::query::QueryResponse;
+use dioxus::prelude::*;
+use std::fmt;
+use wasm_bindgen::JsCast;
+
+const EXPORT_PAGE_SIZE: usize = 1000;
+const MAX_EXPORT_HITS: usize = 10_000;
+/// Tooltip of the "Up to" scope when the search cannot be re-run page by page.
+const UP_TO_UNAVAILABLE: &str =
+    "Only the current hits can be exported for Elasticsearch DSL and multi-index searches";
+/// How long a download's Blob URL stays valid after the click.
+const REVOKE_URL_DELAY_MS: u32 = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportFormat {
+    Csv,
+    Ndjson,
+    Json,
+}
+
+impl fmt::Display for ExportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ExportFormat::Csv => write!(f, "CSV"),
+            ExportFormat::Ndjson => write!(f, "NDJSON"),
+            ExportFormat::Json => write!(f, "JSON"),
+        }
+    }
+}
+
+impl ExportFormat {
+    pub const ALL: [ExportFormat; 3] =
+        [ExportFormat::Csv, ExportFormat::Ndjson, ExportFormat::Json];
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Ndjson => "ndjson",
+            ExportFormat::Json => "json",
+        }
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv",
+            ExportFormat::Ndjson => "application/x-ndjson",
+            ExportFormat::Json => "application/json",
+        }
+    }
+
+    pub fn render(&self, hits: &[serde_json::Value], columns: &[String]) -> String {
+        match self {
+            ExportFormat::Csv => to_csv(hits, columns),
+            ExportFormat::Ndjson => hits.iter().map(|hit| format!("{hit}\n")).collect(),
+            ExportFormat::Json => serde_json::to_string_pretty(hits).unwrap_or_default(),
+        }
+    }
+}
+
+/// Flattens nested objects into dotted keys. Arrays are kept as JSON text
+/// since they have no natural column layout.
+fn flatten_into(
+    prefix: &str,
+    value: &serde_json::Value,
+    out: &mut Vec<(String, serde_json::Value)>,
+) {
+    match value {
+        serde_json::Value::Object(obj) => {
+            for (k, v) in obj {
+                let key = if prefix.is_empty() {
+                    k.clone()
+                } else {
+                    format!("{prefix}.{k}")
+                };
+                flatten_into(&key, v, out);
+            }
+        }
+        _ => out.push((prefix.to_string(), value.clone())),
+    }
+}
+
+pub fn flatten_hit(hit: &serde_json::Value) -> Vec<(String, serde_json::Value)> {
+    let mut out = Vec::new();
+    flatten_into("", hit, &mut out);
+    out
+}
+
+/// Column names of all hits in first-seen order.
+pub fn hit_columns(hits: &[serde_json::Value]) -> Vec<String> {
+    let mut columns: Vec<String> = Vec::new();
+    for hit in hits {
+        for (key, _) in flatten_hit(hit) {
+            if !columns.contains(&key) {
+                columns.push(key);
+            }
+        }
+    }
+    columns
+}
+
+fn csv_cell(value: &serde_json::Value) -> String {
+    let text = match value {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Null => String::new(),
+        _ => value.to_string(),
+    };
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text
+    }
+}
+
+fn to_csv(hits: &[serde_json::Value], columns: &[String]) -> String {
+    let header = columns
+        .iter()
+        .map(|column| csv_cell(&serde_json::Value::String(column.clone())))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let mut csv = header;
+    csv.push('\n');
+    for hit in hits {
+        let fields = flatten_hit(hit);
+        let row = columns
+            .iter()
+            .map(|column| {
+                fields
+                    .iter()
+                    .find(|(key, _)| key == column)
+                    .map(|(_, value)| csv_cell(value))
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        csv.push_str(&row);
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Hands `content` to the browser as a file download through a Blob URL.
+pub fn download(filename: &str, mime_type: &str, content: &str) -> anyhow::Result<()> {
+    let js_err = |e: wasm_bindgen::JsValue| anyhow::anyhow!("{e:?}");
+
+    let window = web_sys::window().ok_or_else(|| anyhow::anyhow!("no window"))?;
+    let document = window
+        .document()
+        .ok_or_else(|| anyhow::anyhow!("no document"))?;
+
+    let parts = serde_wasm_bindgen::to_value(&[content]).map_err(|e| anyhow::anyhow!("{e:?}"))?;
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let blob =
+        web_sys::Blob::new_with_str_sequence_and_options(&parts, &options).map_err(js_err)?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob).map_err(js_err)?;
+
+    let anchor = document
+        .create_element("a")
+        .map_err(js_err)?
+        .dyn_into::<web_sys::HtmlAnchorElement>()
+        .map_err(|_| anyhow::anyhow!("not an anchor element"))?;
+    anchor.set_href(&url);
+    anchor.set_download(filename);
+    anchor.click();
+
+    // Some browsers start reading the Blob only after `click` returns.
+    gloo_timers::callback::Timeout::new(REVOKE_URL_DELAY_MS, move || {
+        let _ = web_sys::Url::revoke_object_url(&url);
+    })
+    .forget();
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ExportScope {
+    CurrentHits,
+    UpTo(usize),
+}
+
+async fn fetch_hits(
+    index_id: &str,
+    timestamp_field: Option<&str>,
+    query: &str,
+    date_range: Option<DateRange>,
+    limit: usize,
+    mut progress: Signal<usize>,
+) -> anyhow::Result<Vec<serde_json::Value>> {
+    let request = search_request(
+        index_id,
+        timestamp_field,
+        query,
+        EXPORT_PAGE_SIZE,
+        date_range,
+    );
+    let mut hits = Vec::new();
+
+    while hits.len() < limit {
+        let page_size = EXPORT_PAGE_SIZE.min(limit - hits.len());
+        let page = request
+            .clone()
+            .start_offset(hits.len())
+            .max_hits(page_size)
+            .fetch()
+            .await?;
+        let received = page.hits.len();
+        hits.extend(page.hits);
+        progress.set(hits.len());
+
+        if received < page_size {
+            break;
+        }
+    }
+
+    Ok(hits)
+}
+
+#[component]
+pub fn ExportMenu(
+    query: Signal<String>,
+    selected_index: Signal<Option<String>>,
+    date_range: Signal<Option<DateRange>>,
+    query_results: Signal<QueryResponse>,
+    query_language: Signal<QueryLanguage>,
+) -> Element {
+    let mut is_open = use_signal(|| false);
+    let mut format = use_signal(|| ExportFormat::Csv);
+    let mut scope = use_signal(|| ExportScope::CurrentHits);
+    let mut excluded_columns: Signal<Vec<String>> = use_signal(Vec::new);
+    let mut progress = use_signal(|| 0usize);
+    let mut exporting = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let metadata = use_metadata_cache();
+    let columns = use_memo(move || hit_columns(&query_results.read().hits));
+
+    // Paging re-runs the query string search, which only targets one index.
+    let can_fetch = move || {
+        query_language() == QueryLanguage::QueryString
+            && selected_index().is_some_and(|index_id| !is_multi_index(&index_id))
+    };
+
+    let run_export = move |_| {
+        let Some(index_id) = selected_index() else {
+            return;
+        };
+        let format = format();
+        let scope = if can_fetch() {
+            scope()
+        } else {
+            ExportScope::CurrentHits
+        };
+        exporting.set(true);
+        progress.set(0);
+        error.set(None);
+
+        spawn(async move {
+            let hits = match scope {
+                ExportScope::CurrentHits => Ok(query_results.read().hits.clone()),
+                ExportScope::UpTo(limit) => {
+                    let timestamp_field = metadata.timestamp_field(&index_id);
+                    fetch_hits(
+                        &index_id,
+                        timestamp_field.as_deref(),
+                        &query(),
+                        date_range(),
+                        limit,
+                        progress,
+                    )
+                    .await
+                }
+            };
+
+            let result = hits.and_then(|hits| {
+                let selected: Vec<String> = hit_columns(&hits)
+                    .into_iter()
+                    .filter(|column| !excluded_columns.read().contains(column))
+                    .collect();
+                let name =
+                    index_id.replace(|c: char| !c.is_alphanumeric() && c != '-' && c != '_', "_");
+                let filename = format!("{name}-hits.{}", format.extension());
+                download(
+                    &filename,
+                    format.mime_type(),
+                    &format.render(&hits, &selected),
+                )
+            });
+
+            if let Err(e) = result {
+                error.set(Some(e.to_string()));
+            } else {
+                is_open.set(false);
+            }
+            exporting.set(false);
+        });
+    };
+
+    let disabled = selected_index.read().is_none();
+    let can_fetch = can_fetch();
+
+    rsx! {
+        div { position: "relative", display: "inline-block", margin_right: "30px",
+            button {
+                r#type: "button",
+                border: "0",
+                border_radius: "4px",
+                padding: "6px 16px",
+                letter_spacing: "0.02857em",
+                color: if disabled { "#4C4D4D" } else { "white" },
+                background_color: if disabled { "#151718" } else { "black" },
+                cursor: if disabled { "not-allowed" } else { "pointer" },
+                disabled,
+                onclick: move |_| is_open.toggle(),
+                "EXPORT"
+            }
+
+            if is_open() {
+                div {
+                    position: "absolute",
+                    top: "100%",
+                    right: 0,
+                    z_index: 1300,
+                    width: "320px",
+                    padding: "12px 16px",
+                    background_color: "#1e1e1e",
+                    border: "1px solid #404040",
+                    border_radius: "4px",
+                    box_shadow: "0 4px 6px rgba(0, 0, 0, 0.3)",
+                    display: "flex",
+                    flex_direction: "column",
+                    gap: "12px",
+
+                    div { display: "flex", gap: "8px",
+                        for choice in ExportFormat::ALL {
+                            button {
+                                key: "{choice}",
+                                flex: 1,
+                                padding: "6px 0",
+                                border_radius: "4px",
+                                cursor: "pointer",
+                                border: if choice == format() { "1px solid rgb(25, 118, 210)" } else { "1px solid #404040" },
+                                background_color: if choice == format() { "rgba(25, 118, 210, 0.2)" } else { "transparent" },
+                                onclick: move |_| format.set(choice),
+                                "{choice}"
+                            }
+                        }
+                    }
+
+                    div { display: "flex", flex_direction: "column", gap: "6px",
+                        label { display: "flex", align_items: "center", gap: "8px", cursor: "pointer",
+                            input {
+                                r#type: "radio",
+                                name: "export-scope",
+                                checked: !can_fetch || scope() == ExportScope::CurrentHits,
+                                onchange: move |_| scope.set(ExportScope::CurrentHits),
+                            }
+                            "Current hits ({query_results.read().hits.len()})"
+                        }
+                        label {
+                            display: "flex",
+                            align_items: "center",
+                            gap: "8px",
+                            cursor: if can_fetch { "pointer" } else { "not-allowed" },
+                            color: if can_fetch { "inherit" } else { "#5F6060" },
+                            title: if can_fetch { "" } else { UP_TO_UNAVAILABLE },
+                            input {
+                                r#type: "radio",
+                                name: "export-scope",
+                                disabled: !can_fetch,
+                                checked: can_fetch && matches!(scope(), ExportScope::UpTo(_)),
+                                onchange: move |_| scope.set(ExportScope::UpTo(EXPORT_PAGE_SIZE)),
+                            }
+                            "Up to"
+                            input {
+                                r#type: "number",
+                                background_color: "#2d2d2d",
+                                color: "#f8f9fa",
+                                border: "1px solid #404040",
+                                border_radius: "4px",
+                                padding: "2px 8px",
+                                width: "90px",
+                                disabled: !can_fetch,
+                                min: "1",
+                                max: "{MAX_EXPORT_HITS}",
+                                value: match scope() {
+                                    ExportScope::UpTo(limit) => limit.to_string(),
+                                    ExportScope::CurrentHits => EXPORT_PAGE_SIZE.to_string(),
+                                },
+                                oninput: move |e| {
+                                    if let Ok(limit) = e.value().parse::<usize>() {
+                                        scope.set(ExportScope::UpTo(limit.clamp(1, MAX_EXPORT_HITS)));
+                                    }
+                                },
+                            }
+                            "hits"
+                        }
+                    }
+
+                    if format() == ExportFormat::Csv && !columns.read().is_empty() {
+                        div {
+                            p { color: "#5F6060", font_size: "13px", margin_bottom: "4px", "Columns" }
+                            div { max_height: "160px", overflow_y: "auto",
+                                for column in columns() {
+                                    label {
+                                        key: "{column}",
+                                        display: "flex",
+                                        align_items: "center",
+                                        gap: "8px",
+                                        font_size: "12px",
+                                        cursor: "pointer",
+                                        input {
+                                            r#type: "checkbox",
+                                            checked: !excluded_columns.read().contains(&column),
+                                            onchange: {
+                                                let column = column.clone();
+                                                move |_| {
+                                                    let mut excluded = excluded_columns.write();
+                                                    if let Some(pos) = excluded.iter().position(|c| c == &column) {
+                                                        excluded.remove(pos);
+                                                    } else {
+                                                        excluded.push(column.clone());
+                                                    }
+                                                }
+                                            },
+                                        }
+                                        "{column}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    if let Some(error) = error() {
+                        p { color: "#ff6b6b", font_size: "12px", "{error}" }
+                    }
+
+                    button {
+                        r#type: "button",
+                        border: "0",
+                        border_radius: "4px",
+                        padding: "6px 16px",
+                        color: "white",
+                        background_color: "rgb(25, 118, 210)",
+                        cursor: if exporting() { "progress" } else { "pointer" },
+                        disabled: exporting(),
+                        onclick: run_export,
+                        if exporting() {
+                            "Fetched {progress()} hits..."
+                        } else {
+                            "DOWNLOAD"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn flatten_hit_uses_dotted_keys_and_keeps_arrays() {
+        let hit = json!({ "resource": { "service": { "name": "api" } }, "tags": ["a", "b"] });
+        assert_eq!(
+            flatten_hit(&hit),
+            vec![
+                ("resource.service.name".to_string(), json!("api")),
+                ("tags".to_string(), json!(["a", "b"])),
+            ]
+        );
+    }
+
+    #[test]
+    fn hit_columns_are_in_first_seen_order() {
+        let hits = [
+            json!({ "b": 1 }),
+            json!({ "a": { "c": 2 } }),
+            json!({ "b": 3 }),
+        ];
+        assert_eq!(hit_columns(&hits), vec!["b", "a.c"]);
+    }
+
+    #[test]
+    fn csv_cell_quotes_only_when_needed() {
+        assert_eq!(csv_cell(&json!("plain")), "plain");
+        assert_eq!(csv_cell(&json!("a,b")), "\"a,b\"");
+        assert_eq!(csv_cell(&json!("say \"hi\"")), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_cell(&json!("two\nlines")), "\"two\nlines\"");
+        assert_eq!(csv_cell(&json!(null)), "");
+        assert_eq!(csv_cell(&json!(1.5)), "1.5");
+        assert_eq!(csv_cell(&json!([1, 2])), "\"[1,2]\"");
+    }
+
+    #[test]
+    fn to_csv_leaves_missing_fields_empty() {
+        let hits = [
+            json!({ "level": "info", "msg": "a, b" }),
+            json!({ "level": "warn" }),
+        ];
+        let columns = hit_columns(&hits);
+        assert_eq!(to_csv(&hits, &columns), "level,msg\ninfo,\"a, b\"\nwarn,\n");
+    }
+}
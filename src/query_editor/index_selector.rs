@@ -1,30 +1,65 @@
-use crate::icons;
-use crate::quickwit::QuickwitApi;
-use crate::quickwit::index::Index;
+use crate::metadata::use_metadata_cache;
+use crate::quickwit::index::{
+    FieldMapping, Index, is_multi_index, matches_index_expr, matches_index_pattern,
+};
+use crate::{Route, icons};
 use chrono::{TimeZone, Utc};
 use dioxus::prelude::*;
 use dioxus_logger::tracing::info;
 use wasm_bindgen::prelude::*;
 
+/// Splits the selected index expression into its comma-separated entries.
+fn selected_entries(selected_index: Option<String>) -> Vec<String> {
+    selected_index
+        .map(|expr| {
+            expr.split(',')
+                .map(|entry| entry.trim().to_string())
+                .filter(|entry| !entry.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn set_entries(mut selected_index: Signal<Option<String>>, entries: Vec<String>) {
+    if entries.is_empty() {
+        selected_index.set(None);
+    } else {
+        selected_index.set(Some(entries.join(",")));
+    }
+}
+
+fn toggle_entry(selected_index: Signal<Option<String>>, entry: String) {
+    let mut entries = selected_entries(selected_index());
+    match entries.iter().position(|selected| selected == &entry) {
+        Some(pos) => {
+            entries.remove(pos);
+        }
+        None => entries.push(entry),
+    }
+    set_entries(selected_index, entries);
+}
+
 #[component]
 fn IndexInput(search_value: Signal<String>, selected_index: Signal<Option<String>>) -> Element {
     let mut is_dropdown_open = use_signal(|| false);
     let mut is_hovered = use_signal(|| false);
-    let data = use_resource(|| async { QuickwitApi::indexes().await.unwrap() });
+    let metadata = use_metadata_cache();
 
     let filtered_indexes = move || -> Vec<_> {
-        data.read()
-            .as_ref()
+        let search = search_value().to_lowercase();
+        metadata
+            .indexes()
+            .and_then(Result::ok)
             .map(|indexes| {
                 indexes
                     .iter()
                     .filter(|index| {
-                        index
-                            .index_config
-                            .index_id
-                            .to_lowercase()
-                            .contains(&search_value().to_lowercase())
-                            || selected_index.read().is_some()
+                        let index_id = index.index_config.index_id.to_lowercase();
+                        if search.contains('*') {
+                            matches_index_pattern(&search, &index_id)
+                        } else {
+                            index_id.contains(&search)
+                        }
                     })
                     .cloned()
                     .collect()
@@ -32,6 +67,25 @@ fn IndexInput(search_value: Signal<String>, selected_index: Signal<Option<String
             .unwrap_or_default()
     };
 
+    // A typed pattern is offered as an entry of its own so it keeps matching
+    // indexes created after the search was saved.
+    let pattern = move || -> Option<String> {
+        let search = search_value().trim().to_string();
+        search.contains('*').then_some(search)
+    };
+
+    let mut add_typed_entry = move || {
+        let search = search_value().trim().to_string();
+        let exists = filtered_indexes()
+            .iter()
+            .any(|index| index.index_config.index_id == search);
+        if (exists || pattern().is_some()) && !selected_entries(selected_index()).contains(&search)
+        {
+            toggle_entry(selected_index, search);
+            search_value.set(String::new());
+        }
+    };
+
     let handle_blur = move |_| {
         spawn(async move {
             gloo_timers::future::TimeoutFuture::new(200).await;
@@ -44,13 +98,6 @@ fn IndexInput(search_value: Signal<String>, selected_index: Signal<Option<String
                 if tag_name != "input" && tag_name != "button" {
                     is_dropdown_open.set(false);
                 }
-
-                if filtered_indexes()
-                    .iter()
-                    .all(|index| index.index_config.index_id != search_value())
-                {
-                    search_value.set(selected_index().unwrap_or_default());
-                }
             } else {
                 is_dropdown_open.set(false);
             }
@@ -58,6 +105,41 @@ fn IndexInput(search_value: Signal<String>, selected_index: Signal<Option<String
     };
 
     rsx! {
+        if selected_index.read().is_some() {
+            div {
+                display: "flex",
+                flex_wrap: "wrap",
+                gap: "4px",
+                margin_bottom: "6px",
+                max_width: "300px",
+                for entry in selected_entries(selected_index()) {
+                    span {
+                        key: "{entry}",
+                        display: "inline-flex",
+                        align_items: "center",
+                        gap: "2px",
+                        padding: "2px 4px 2px 8px",
+                        border: "1px solid #323230",
+                        border_radius: "12px",
+                        font_size: "12px",
+                        "{entry}"
+                        button {
+                            background: "transparent",
+                            border: "none",
+                            color: "#e8e6e38a",
+                            cursor: "pointer",
+                            display: "flex",
+                            align_items: "center",
+                            onclick: {
+                                let entry = entry.clone();
+                                move |_| toggle_entry(selected_index, entry.clone())
+                            },
+                            icons::Close {}
+                        }
+                    }
+                }
+            }
+        }
         div {
             onmouseenter: move |_| {
                 is_hovered.set(true);
@@ -79,21 +161,21 @@ fn IndexInput(search_value: Signal<String>, selected_index: Signal<Option<String
                 color: "#f8f9fa",
                 outline: "none",
                 padding: "10px",
-                placeholder: "Select an index",
+                placeholder: "Select indexes or a pattern",
                 value: "{search_value}",
                 oninput: move |evt| {
                     search_value.set(evt.value());
-                    if filtered_indexes()
-                        .iter()
-                        .any(|index| { index.index_config.index_id == search_value() })
-                    {
-                        selected_index.set(Some(search_value()));
+                    is_dropdown_open.set(true);
+                },
+                onkeydown: move |evt| {
+                    if evt.key() == Key::Enter {
+                        add_typed_entry();
                     }
                 },
                 onblur: handle_blur,
                 onfocus: move |_| is_dropdown_open.set(true),
             }
-            if !search_value.read().is_empty() && is_hovered() {
+            if selected_index.read().is_some() && is_hovered() {
                 button {
                     background: "transparent",
                     border: "none",
@@ -135,19 +217,13 @@ fn IndexInput(search_value: Signal<String>, selected_index: Signal<Option<String
             if is_dropdown_open() {
                 IndexDropdown {
                     indexes: filtered_indexes(),
-                    on_select: move |index_id: String| {
-                        search_value.set(index_id);
-                        if filtered_indexes()
-                            .iter()
-                            .any(|index| index.index_config.index_id == search_value())
-                        {
-                            selected_index.set(Some(search_value()));
-                        } else {
-                            selected_index.set(None);
-                        }
-                        is_dropdown_open.set(false);
+                    selected: selected_entries(selected_index()),
+                    pattern: pattern(),
+                    on_select: move |entry: String| {
+                        toggle_entry(selected_index, entry);
+                        search_value.set(String::new());
                     },
-                    is_loading: data.read().is_none(),
+                    is_loading: metadata.indexes().is_none(),
                 }
             }
         }
@@ -171,7 +247,20 @@ pub fn IndexSelector(
                 padding_top: "18px",
                 padding_left: "24px",
                 padding_right: "24px",
-                p { margin_bottom: "10px", "Index ID" }
+                div {
+                    display: "flex",
+                    justify_content: "space-between",
+                    align_items: "baseline",
+                    margin_bottom: "10px",
+                    p { "Index ID" }
+                    if let Some(index_id) = selected_index().filter(|id| !is_multi_index(id)) {
+                        Link {
+                            to: Route::IndexDetail { index_id },
+                            style: "font-size: 12px; color: #5F6060; text-decoration: underline;",
+                            "Details"
+                        }
+                    }
+                }
                 IndexInput { search_value, selected_index }
             }
             FieldsPanel { selected_index_id: selected_index() }
@@ -182,6 +271,8 @@ pub fn IndexSelector(
 #[component]
 fn IndexDropdown(
     indexes: Vec<Index>,
+    selected: Vec<String>,
+    pattern: Option<String>,
     on_select: EventHandler<String>,
     is_loading: bool,
 ) -> Element {
@@ -194,13 +285,30 @@ fn IndexDropdown(
             overflow_y: "auto",
             box_shadow: "0 4px 6px rgba(0, 0, 0, 0.3)",
 
+            if let Some(pattern) = pattern {
+                div {
+                    padding: "12px 16px",
+                    cursor: "pointer",
+                    color: "#f8f9fa",
+                    background_color: "transparent",
+                    border_bottom: "1px solid #323230",
+                    onclick: {
+                        let pattern = pattern.clone();
+                        move |_| {
+                            on_select.call(pattern.clone());
+                        }
+                    },
+                    "Add pattern {pattern} ({indexes.len()} indexes)"
+                }
+            }
+
             for idx in indexes {
                 div {
                     key: "{idx.index_config.index_id}",
                     padding: "12px 16px",
                     cursor: "pointer",
                     color: "#f8f9fa",
-                    background_color: "transparent",
+                    background_color: if selected.contains(&idx.index_config.index_id) { "#2d2d2d" } else { "transparent" },
                     overflow: "hidden",
                     onclick: {
                         let index_id = idx.index_config.index_id.clone();
@@ -218,19 +326,26 @@ fn IndexDropdown(
 #[component]
 fn FieldsPanel(selected_index_id: Option<String>) -> Element {
     let mut is_fields_expanded = use_signal(|| false);
-    let data = use_resource(|| async { QuickwitApi::indexes().await.unwrap() });
+    let metadata = use_metadata_cache();
 
-    let get_selected_index = || -> Option<Index> {
-        data.read()
-            .as_ref()
-            .and_then(|indexes| {
-                indexes.iter().find(|index| {
-                    selected_index_id
-                        .as_ref()
-                        .map_or(false, |id| id == &index.index_config.index_id)
-                })
-            })
-            .cloned()
+    // With several indexes selected, the panel lists the union of their fields.
+    let get_selected_fields = || -> Vec<FieldMapping> {
+        let mut fields: Vec<FieldMapping> = Vec::new();
+        if let (Some(indexes), Some(index_expr)) =
+            (metadata.indexes().and_then(Result::ok), &selected_index_id)
+        {
+            for index in indexes
+                .iter()
+                .filter(|index| matches_index_expr(index_expr, &index.index_config.index_id))
+            {
+                for field in &index.index_config.doc_mapping.field_mappings {
+                    if fields.iter().all(|known| known.name != field.name) {
+                        fields.push(field.clone());
+                    }
+                }
+            }
+        }
+        fields
     };
 
     rsx! {
@@ -273,19 +388,8 @@ fn FieldsPanel(selected_index_id: Option<String>) -> Element {
                         padding: "0",
                         margin: "8px 0",
                         overflow_wrap: "break-word",
-                        if let Some(selected_index) = get_selected_index() {
-                            {
-                                selected_index
-                                    .index_config
-                                    .doc_mapping
-                                    .field_mappings
-                                    .iter()
-                                    .map(|field| {
-                                        rsx! {
-                                            li { padding: "4px 0", color: "#f8f9fa", "{field.name}" }
-                                        }
-                                    })
-                            }
+                        for field in get_selected_fields() {
+                            li { padding: "4px 0", color: "#f8f9fa", "{field.name}" }
                         }
                     }
                 }
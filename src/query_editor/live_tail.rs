@@ -0,0 +1,237 @@
+use super::SINGLE_INDEX_ONLY;
+use crate::metadata::use_metadata_cache;
+use crate::quickwit::QuickwitApi;
+use crate::quickwit::index::is_multi_index;
+use crate::quickwit::query::QueryResponse;
+use chrono::{DateTime, Utc};
+use dioxus::prelude::*;
+use dioxus_sdk::utils::timing::use_interval;
+use std::collections::VecDeque;
+use std::time::Duration;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+const MAX_BUFFERED_HITS: usize = 500;
+const RATE_WINDOW_MILLIS: i64 = 30_000;
+
+/// Reads the timestamp field of a hit as fractional seconds since the epoch.
+///
+/// Quickwit returns timestamps in whatever output format the doc mapping
+/// declares, so both RFC 3339 strings and unix numbers in any precision are
+/// accepted.
+pub fn hit_timestamp_secs(hit: &serde_json::Value, field: &str) -> Option<f64> {
+    let value = hit
+        .get(field)
+        .or_else(|| hit.pointer(&format!("/{}", field.replace('.', "/"))))?;
+
+    match value {
+        serde_json::Value::Number(n) => n.as_f64().map(normalize_epoch),
+        serde_json::Value::String(s) => DateTime::parse_from_rfc3339(s)
+            .map(|datetime| datetime.timestamp_micros() as f64 / 1_000_000.0)
+            .ok()
+            .or_else(|| s.parse::<f64>().ok().map(normalize_epoch)),
+        _ => None,
+    }
+}
+
+fn normalize_epoch(value: f64) -> f64 {
+    let magnitude = value.abs();
+    if magnitude >= 1e17 {
+        value / 1e9
+    } else if magnitude >= 1e14 {
+        value / 1e6
+    } else if magnitude >= 1e11 {
+        value / 1e3
+    } else {
+        value
+    }
+}
+
+fn prepend_hits<T>(buffer: &mut Vec<T>, new_hits: Vec<T>) {
+    let mut merged = new_hits;
+    merged.append(buffer);
+    merged.truncate(MAX_BUFFERED_HITS);
+    *buffer = merged;
+}
+
+/// Prepends tailed hits of `index_id` to the results, keeping the index
+/// labels of an earlier multi-index search lined up with their hits.
+fn prepend_results(results: &mut QueryResponse, hits: Vec<serde_json::Value>, index_id: &str) {
+    if !results.hit_indexes.is_empty() {
+        prepend_hits(
+            &mut results.hit_indexes,
+            vec![index_id.to_string(); hits.len()],
+        );
+    }
+    results.num_hits += hits.len() as i64;
+    prepend_hits(&mut results.hits, hits);
+}
+
+#[component]
+pub fn LiveTail(
+    query: Signal<String>,
+    selected_index: Signal<Option<String>>,
+    query_results: Signal<QueryResponse>,
+    paused: Signal<bool>,
+) -> Element {
+    let mut live = use_signal(|| false);
+    let mut in_flight = use_signal(|| false);
+    let mut last_seen: Signal<Option<f64>> = use_signal(|| None);
+    let mut pending: Signal<Vec<serde_json::Value>> = use_signal(Vec::new);
+    let mut samples: Signal<VecDeque<(i64, usize)>> = use_signal(VecDeque::new);
+
+    let metadata = use_metadata_cache();
+
+    let timestamp_field = move || {
+        metadata
+            .index(&selected_index()?)
+            .map(|index| index.index_config.doc_mapping.timestamp_field)
+    };
+
+    use_interval(POLL_INTERVAL, move || {
+        if !live() || in_flight() {
+            return;
+        }
+        // Tailing queries one index, so switching to several stops it.
+        let Some(index_id) = selected_index().filter(|id| !is_multi_index(id)) else {
+            live.set(false);
+            return;
+        };
+        let Some(field) = timestamp_field() else {
+            return;
+        };
+
+        in_flight.set(true);
+        spawn(async move {
+            let since = last_seen().unwrap_or_else(|| Utc::now().timestamp() as f64);
+            let response = QuickwitApi::query(&index_id)
+                .query(&query())
+                .max_hits(MAX_BUFFERED_HITS)
+                .sort_by_field(&field)
+                .start_timestamp(&(since.floor() as i64).to_string())
+                .fetch()
+                .await;
+            in_flight.set(false);
+
+            let Ok(response) = response else {
+                return;
+            };
+
+            // The start bound is inclusive and only has second precision, so hits
+            // sharing the last seen second come back and have to be skipped.
+            let fresh: Vec<_> = response
+                .hits
+                .into_iter()
+                .filter(|hit| match hit_timestamp_secs(hit, &field) {
+                    Some(ts) if ts > since => true,
+                    _ => !query_results.peek().hits.contains(hit) && !pending.peek().contains(hit),
+                })
+                .collect();
+
+            let newest = fresh
+                .iter()
+                .filter_map(|hit| hit_timestamp_secs(hit, &field))
+                .fold(since, f64::max);
+            last_seen.set(Some(newest));
+
+            let now = Utc::now().timestamp_millis();
+            {
+                let mut samples = samples.write();
+                samples.push_back((now, fresh.len()));
+                while samples
+                    .front()
+                    .is_some_and(|(at, _)| now - at > RATE_WINDOW_MILLIS)
+                {
+                    samples.pop_front();
+                }
+            }
+
+            if fresh.is_empty() {
+                return;
+            }
+
+            if paused() {
+                prepend_hits(&mut pending.write(), fresh);
+            } else {
+                prepend_results(&mut query_results.write(), fresh, &index_id);
+            }
+        });
+    });
+
+    use_effect(move || {
+        if !paused() && !pending.peek().is_empty() {
+            let hits = std::mem::take(&mut *pending.write());
+            let index_id = selected_index.peek().clone().unwrap_or_default();
+            prepend_results(&mut query_results.write(), hits, &index_id);
+        }
+    });
+
+    let rate = {
+        let samples = samples.read();
+        let total: usize = samples.iter().map(|(_, count)| count).sum();
+        let span_millis = match (samples.front(), samples.back()) {
+            (Some((first, _)), Some((last, _))) => {
+                (last - first).max(POLL_INTERVAL.as_millis() as i64)
+            }
+            _ => POLL_INTERVAL.as_millis() as i64,
+        };
+        total as f64 / (span_millis as f64 / 1000.0)
+    };
+
+    let multi_index = selected_index().is_some_and(|index_id| is_multi_index(&index_id));
+    let disabled = selected_index.read().is_none() || multi_index;
+
+    rsx! {
+        div { display: "flex", align_items: "center", gap: "12px", margin_right: "30px",
+            button {
+                r#type: "button",
+                border: "0",
+                border_radius: "4px",
+                padding: "6px 16px",
+                letter_spacing: "0.02857em",
+                display: "inline-flex",
+                align_items: "center",
+                gap: "8px",
+                color: if disabled { "#4C4D4D" } else { "white" },
+                background_color: if disabled { "#151718" } else { "black" },
+                cursor: if disabled { "not-allowed" } else { "pointer" },
+                disabled,
+                title: if multi_index { SINGLE_INDEX_ONLY } else { "" },
+                onclick: move |_| {
+                    if live() {
+                        live.set(false);
+                        paused.set(false);
+                    } else {
+                        let newest = timestamp_field()
+                            .and_then(|field| {
+                                query_results
+                                    .read()
+                                    .hits
+                                    .iter()
+                                    .filter_map(|hit| hit_timestamp_secs(hit, &field))
+                                    .reduce(f64::max)
+                            });
+                        last_seen.set(newest);
+                        samples.write().clear();
+                        live.set(true);
+                    }
+                },
+                span {
+                    width: "8px",
+                    height: "8px",
+                    border_radius: "50%",
+                    background_color: if live() { "#e5484d" } else { "#5F6060" },
+                }
+                "LIVE"
+            }
+            if live() {
+                span { color: "#5F6060", font_size: "13px",
+                    if paused() {
+                        "Paused · {pending.read().len()} new hits"
+                    } else {
+                        "{rate:.1} docs/s"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,17 +1,41 @@
+mod copy_request;
+mod delete_matching;
+mod export;
 mod index_selector;
+mod live_tail;
 mod time_range_select;
 
+use crate::auto_refresh::{AutoRefreshSelect, RefreshInterval, use_auto_refresh};
 use crate::document::eval;
 use crate::icons;
-use crate::quickwit::QuickwitApi;
+use crate::metadata::use_metadata_cache;
+use crate::quickwit::elastic::ElasticRequest;
+use crate::quickwit::in_flight::{is_cancelled, supersede};
+use crate::quickwit::index::is_multi_index;
 use crate::quickwit::query::QueryResponse;
+use crate::quickwit::{QueryBuilder, QuickwitApi};
 use chrono::{DateTime, TimeZone, Utc};
+use copy_request::CopyRequestMenu;
+use delete_matching::DeleteMatchingButton;
 use dioxus::prelude::*;
 use dioxus_logger::tracing::info;
+use export::ExportMenu;
 use index_selector::IndexSelector;
+use live_tail::LiveTail;
 use num_format::{Locale, ToFormattedString};
+use std::rc::Rc;
 use time_range_select::DateRange;
 use wasm_bindgen::prelude::*;
+use web_sys::AbortSignal;
+
+/// Searches run by the editor share this slot, so a new one aborts any still
+/// in flight and only the latest lands in the results.
+const SEARCH_SLOT: &str = "query-editor";
+
+/// Tooltip of the controls that only work against a single index.
+const SINGLE_INDEX_ONLY: &str = "Select a single index to use this";
+
+pub(crate) use copy_request::copy_to_clipboard;
 
 #[component]
 pub fn CollapseAllCheckbox(collapse_all: Signal<bool>) -> Element {
@@ -118,6 +142,113 @@ pub fn MaxHitsSelector(max_hits: Signal<usize>) -> Element {
     }
 }
 
+/// Builds the search described by the editor state, newest first when the
+/// index has a timestamp field. Relative date ranges are resolved against the
+/// current time on every call.
+fn search_request<'a>(
+    index_id: &'a str,
+    timestamp_field: Option<&str>,
+    query: &str,
+    max_hits: usize,
+    date_range: Option<DateRange>,
+) -> QueryBuilder<'a> {
+    let (start, end) = match date_range.map(|date_range| date_range.bounds_secs()) {
+        Some((start, end)) => (
+            start.to_string(),
+            end.map(|end| end.to_string()).unwrap_or_default(),
+        ),
+        None => (String::new(), String::new()),
+    };
+    QuickwitApi::query(index_id)
+        .query(query)
+        .max_hits(max_hits)
+        .sort_by_field(timestamp_field.unwrap_or_default())
+        .start_timestamp(&start)
+        .end_timestamp(&end)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QueryLanguage {
+    QueryString,
+    ElasticDsl,
+}
+
+async fn run_dsl(index_id: &str, dsl: &str, signal: AbortSignal) -> anyhow::Result<QueryResponse> {
+    let request = ElasticRequest::parse(dsl)?;
+    QuickwitApi::elastic(index_id, &request, Some(&signal)).await
+}
+
+/// Searches several indexes through the `_elastic` API, whose hits carry the
+/// index they came from. The time range is only applied when every matched
+/// index shares the same `timestamp_field`, as read from the metadata cache.
+async fn multi_index_search(
+    index_expr: &str,
+    timestamp_field: Option<&str>,
+    query: &str,
+    max_hits: usize,
+    date_range: Option<DateRange>,
+    signal: AbortSignal,
+) -> anyhow::Result<QueryResponse> {
+    let body = search_request(index_expr, timestamp_field, query, max_hits, date_range)
+        .to_elasticsearch(timestamp_field);
+    QuickwitApi::elastic_search(index_expr, &body, Some(&signal))
+        .await
+        .map(Into::into)
+}
+
+#[component]
+fn QueryLanguageToggle(
+    query_language: Signal<QueryLanguage>,
+    query: Signal<String>,
+    dsl: Signal<String>,
+    max_hits: Signal<usize>,
+    date_range: Signal<Option<DateRange>>,
+    selected_index: Signal<Option<String>>,
+) -> Element {
+    let metadata = use_metadata_cache();
+
+    let tab = move |language: QueryLanguage, label: &'static str| {
+        let active = query_language() == language;
+        rsx! {
+            button {
+                r#type: "button",
+                padding: "4px 12px",
+                border: "none",
+                border_bottom: if active { "2px solid rgb(25, 118, 210)" } else { "2px solid transparent" },
+                background: "none",
+                color: if active { "#f8f9fa" } else { "#5F6060" },
+                cursor: "pointer",
+                onclick: move |_| {
+                    // Seed the DSL editor with the equivalent of the current query string,
+                    // time range and max hits included.
+                    if language == QueryLanguage::ElasticDsl && dsl.read().trim().is_empty() {
+                        let index_id = selected_index().unwrap_or_default();
+                        let timestamp_field = metadata.timestamp_field(&index_id);
+                        let body = search_request(
+                                &index_id,
+                                timestamp_field.as_deref(),
+                                &query(),
+                                max_hits(),
+                                date_range(),
+                            )
+                            .to_elasticsearch(timestamp_field.as_deref());
+                        dsl.set(serde_json::to_string_pretty(&body).unwrap_or_default());
+                    }
+                    query_language.set(language);
+                },
+                "{label}"
+            }
+        }
+    };
+
+    rsx! {
+        div { display: "flex", gap: "4px", margin_bottom: "6px",
+            {tab(QueryLanguage::QueryString, "Query string")}
+            {tab(QueryLanguage::ElasticDsl, "Elasticsearch DSL")}
+        }
+    }
+}
+
 #[component]
 pub fn RunButton(
     query: String,
@@ -125,7 +256,12 @@ pub fn RunButton(
     selected_index: Signal<Option<String>>,
     max_hits: Signal<usize>,
     date_range: Signal<Option<DateRange>>,
+    query_language: Signal<QueryLanguage>,
+    dsl: Signal<String>,
+    query_error: Signal<Option<String>>,
 ) -> Element {
+    let metadata = use_metadata_cache();
+
     rsx! {
         div { flex_grow: 1, margin_bottom: "6px",
             button {
@@ -144,20 +280,31 @@ pub fn RunButton(
                 onclick: move |_| {
                     if let Some(selected_index) = selected_index.read().clone() {
                         let query = query.clone();
+                        query_error.set(None);
+                        let signal = supersede(SEARCH_SLOT);
+                        let timestamp_field = metadata.timestamp_field(&selected_index);
                         spawn(async move {
-                            let date_range_str = match date_range() {
-                                Some(date_range) => format!("{}", date_range.to_timestamp_nanos()),
-                                None => "".to_string(),
+                            let timestamp_field = timestamp_field.as_deref();
+                            let results = match query_language() {
+                                QueryLanguage::QueryString if is_multi_index(&selected_index) => {
+                                    search_request(&selected_index, timestamp_field, &query, max_hits(), date_range())
+                                        .push_history();
+                                    multi_index_search(&selected_index, timestamp_field, &query, max_hits(), date_range(), signal)
+                                        .await
+                                }
+                                QueryLanguage::QueryString => {
+                                    search_request(&selected_index, timestamp_field, &query, max_hits(), date_range())
+                                        .abort_signal(signal)
+                                        .execute()
+                                        .await
+                                }
+                                QueryLanguage::ElasticDsl => run_dsl(&selected_index, &dsl(), signal).await,
                             };
-                            let results = QuickwitApi::query(&selected_index)
-                                .max_hits(max_hits())
-                                .sort_by_field("timestamp_nanos")
-                                .start_timestamp(&date_range_str)
-                                .end_timestamp("")
-                                .execute()
-                                .await
-                                .unwrap();
-                            query_results.set(results);
+                            match results {
+                                Ok(results) => query_results.set(results),
+                                Err(e) if is_cancelled(&e) => {}
+                                Err(e) => query_error.set(Some(e.to_string())),
+                            }
                         });
                     }
                 },
@@ -178,11 +325,59 @@ pub fn RunButton(
 pub fn QueryEditor() -> Element {
     let mut query = use_signal(|| "*".to_string());
     let mut query_results = use_signal(|| QueryResponse::default());
-    let mut selected_index = use_signal(|| None);
+    let mut selected_index = use_signal(|| None::<String>);
     let mut max_hits = use_signal(|| 20);
-    let mut search_value = use_signal(|| String::new());
+    let search_value = use_signal(|| String::new());
     let collapse_all = use_signal(|| true);
     let date_range: Signal<Option<DateRange>> = use_signal(|| None);
+    let mut tail_paused = use_signal(|| false);
+    let mut results_container: Signal<Option<Rc<MountedData>>> = use_signal(|| None);
+    let refresh_interval = use_signal(RefreshInterval::default);
+    let query_language = use_signal(|| QueryLanguage::QueryString);
+    let mut dsl = use_signal(String::new);
+    let query_error: Signal<Option<String>> = use_signal(|| None);
+
+    let metadata = use_metadata_cache();
+
+    use_auto_refresh(refresh_interval, move |_| {
+        let Some(index_id) = selected_index() else {
+            return;
+        };
+        let signal = supersede(SEARCH_SLOT);
+        let timestamp_field = metadata.timestamp_field(&index_id);
+        spawn(async move {
+            let timestamp_field = timestamp_field.as_deref();
+            let results = match query_language() {
+                QueryLanguage::QueryString if is_multi_index(&index_id) => {
+                    multi_index_search(
+                        &index_id,
+                        timestamp_field,
+                        &query(),
+                        max_hits(),
+                        date_range(),
+                        signal,
+                    )
+                    .await
+                }
+                QueryLanguage::QueryString => {
+                    search_request(
+                        &index_id,
+                        timestamp_field,
+                        &query(),
+                        max_hits(),
+                        date_range(),
+                    )
+                    .abort_signal(signal)
+                    .fetch()
+                    .await
+                }
+                QueryLanguage::ElasticDsl => run_dsl(&index_id, &dsl(), signal).await,
+            };
+            if let Ok(results) = results {
+                query_results.set(results);
+            }
+        });
+    });
 
     use_effect(move || {
         if let Some(window) = web_sys::window() {
@@ -202,7 +397,6 @@ pub fn QueryEditor() -> Element {
                     }
 
                     if let Some(idx) = params.get("index") {
-                        search_value.set(idx.to_string());
                         selected_index.set(Some(idx.to_string()));
                     }
 
@@ -218,10 +412,12 @@ pub fn QueryEditor() -> Element {
                                 .sort_by_field("timestamp_nanos")
                                 .start_timestamp(&date_range_str)
                                 .end_timestamp("")
+                                .abort_signal(supersede(SEARCH_SLOT))
                                 .execute()
-                                .await
-                                .unwrap();
-                            query_results.set(results);
+                                .await;
+                            if let Ok(results) = results {
+                                query_results.set(results);
+                            }
                         }
                     });
                 }
@@ -244,32 +440,109 @@ pub fn QueryEditor() -> Element {
                         selected_index,
                         max_hits,
                         date_range,
+                        query_language,
+                        dsl,
+                        query_error,
+                    }
+                    LiveTail {
+                        query,
+                        selected_index,
+                        query_results,
+                        paused: tail_paused,
                     }
                     MaxHitsSelector { max_hits }
+                    AutoRefreshSelect { interval: refresh_interval }
                     CollapseAllCheckbox { collapse_all }
                     time_range_select::DateRangeButton {
                         date_range,
                     }
+                    ExportMenu {
+                        query,
+                        selected_index,
+                        date_range,
+                        query_results,
+                        query_language,
+                    }
+                    CopyRequestMenu {
+                        query,
+                        selected_index,
+                        max_hits,
+                        date_range,
+                        query_language,
+                        dsl,
+                    }
+                    DeleteMatchingButton {
+                        query,
+                        selected_index,
+                        date_range,
+                        query_language,
+                    }
+                }
+                QueryLanguageToggle {
+                    query_language,
+                    query,
+                    dsl,
+                    max_hits,
+                    date_range,
+                    selected_index,
                 }
-                textarea {
-                    value: "{query()}",
-                    oninput: move |e| query.set(e.value()),
-                    width: "100%",
-                    min_height: "100px",
-                    height: "100px",
-                    max_height: "100px",
-                    background_color: "#2d2d2d",
-                    color: "#f8f9fa",
-                    border: "1px solid #404040",
-                    resize: "none",
-                    padding: "5px",
-                    border_radius: "4px",
-                    margin_bottom: "12px",
+                match query_language() {
+                    QueryLanguage::QueryString => rsx! {
+                        textarea {
+                            value: "{query()}",
+                            oninput: move |e| query.set(e.value()),
+                            width: "100%",
+                            min_height: "100px",
+                            height: "100px",
+                            max_height: "100px",
+                            background_color: "#2d2d2d",
+                            color: "#f8f9fa",
+                            border: "1px solid #404040",
+                            resize: "none",
+                            padding: "5px",
+                            border_radius: "4px",
+                            margin_bottom: "12px",
+                        }
+                    },
+                    QueryLanguage::ElasticDsl => rsx! {
+                        textarea {
+                            value: "{dsl()}",
+                            oninput: move |e| dsl.set(e.value()),
+                            spellcheck: "false",
+                            width: "100%",
+                            min_height: "200px",
+                            height: "200px",
+                            max_height: "200px",
+                            background_color: "#2d2d2d",
+                            color: "#f8f9fa",
+                            border: "1px solid #404040",
+                            resize: "none",
+                            padding: "5px",
+                            border_radius: "4px",
+                            margin_bottom: "12px",
+                            font_size: "12px",
+                        }
+                    },
+                }
+                if let Some(error) = query_error() {
+                    p { color: "#ff6b6b", margin_bottom: "10px", "{error}" }
                 }
                 HitCount { query_results: query_results.read().clone() }
-                div { style: "flex: 1; min-height: 0; overflow-y: auto;",
+                div {
+                    style: "flex: 1; min-height: 0; overflow-y: auto;",
+                    onmounted: move |e| results_container.set(Some(e.data())),
+                    onscroll: move |_| {
+                        if let Some(container) = results_container() {
+                            spawn(async move {
+                                if let Ok(offset) = container.get_scroll_offset().await {
+                                    tail_paused.set(offset.y > 0.0);
+                                }
+                            });
+                        }
+                    },
                     ResultTable {
                         hits: query_results.read().hits.clone(),
+                        hit_indexes: query_results.read().hit_indexes.clone(),
                         collapse_all,
                     }
                 }
@@ -308,7 +581,11 @@ fn timestamp_nanos_u64_to_date_parts(timestamp_nanos: i64) -> (String, String) {
 }
 
 #[component]
-fn ResultTable(hits: Vec<serde_json::Value>, collapse_all: Signal<bool>) -> Element {
+fn ResultTable(
+    hits: Vec<serde_json::Value>,
+    hit_indexes: Vec<String>,
+    collapse_all: Signal<bool>,
+) -> Element {
     rsx! {
         div {
             display: "flex",
@@ -316,7 +593,7 @@ fn ResultTable(hits: Vec<serde_json::Value>, collapse_all: Signal<bool>) -> Elem
             overflow_y: "auto",
             flex_grow: 1,
             max_height: "calc(100% - 50px)",
-            for hit in hits.iter() {
+            for (i, hit) in hits.iter().enumerate() {
                 {
                     let mut collapsed = use_signal(|| collapse_all());
                     use_effect(move || {
@@ -370,6 +647,18 @@ fn ResultTable(hits: Vec<serde_json::Value>, collapse_all: Signal<bool>) -> Elem
                                         }
                                     }
                                 }
+                                if let Some(index_id) = hit_indexes.get(i) {
+                                    span {
+                                        margin_top: "4px",
+                                        margin_left: "24px",
+                                        padding: "0 4px",
+                                        font_size: "11px",
+                                        color: "#B5AFA7",
+                                        background_color: "#242728",
+                                        border_radius: "2px",
+                                        "{index_id}"
+                                    }
+                                }
                             }
                             div { style: "padding: 8px; word-break: break-all; flex-grow: 1;",
                                 if let serde_json::Value::Object(obj) = hit {
@@ -129,6 +129,14 @@ impl DateRange {
         }
     }
 
+    /// Start and optional end of the range, in seconds.
+    pub fn bounds_secs(&self) -> (i64, Option<i64>) {
+        match self {
+            DateRange::Custom { start, end } => (start.timestamp(), Some(end.timestamp())),
+            relative => (relative.to_timestamp_nanos(), None),
+        }
+    }
+
     pub fn to_timestamp_nanos(&self) -> i64 {
         let now = Utc::now();
 
@@ -1,4 +1,324 @@
-#[derive(serde::Deserialize, Debug)]
+use std::collections::BTreeMap;
+
+#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
 pub struct Cluster {
     pub cluster_id: String,
+    #[serde(default)]
+    pub self_node_id: String,
+    #[serde(default)]
+    pub ready_nodes: Vec<ChitchatId>,
+    #[serde(default)]
+    pub live_nodes: Vec<ChitchatId>,
+    #[serde(default)]
+    pub dead_nodes: Vec<ChitchatId>,
+    #[serde(default)]
+    pub chitchat_state_snapshot: ChitchatSnapshot,
+}
+
+#[derive(serde::Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct ChitchatSnapshot {
+    #[serde(default)]
+    pub node_state_snapshots: Vec<NodeStateSnapshot>,
+}
+
+#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct NodeStateSnapshot {
+    pub chitchat_id: ChitchatId,
+    pub node_state: NodeState,
+}
+
+#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct ChitchatId {
+    pub node_id: String,
+    pub generation_id: u64,
+    pub gossip_advertise_addr: String,
+}
+
+#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct NodeState {
+    #[serde(default)]
+    pub key_values: BTreeMap<String, VersionedValue>,
+}
+
+#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct VersionedValue {
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NodeStatus {
+    Ready,
+    /// Gossiping but not ready to serve requests yet.
+    Live,
+    Dead,
+}
+
+impl NodeStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NodeStatus::Ready => "Ready",
+            NodeStatus::Live => "Not ready",
+            NodeStatus::Dead => "Dead",
+        }
+    }
+}
+
+/// A cluster member as seen through the chitchat state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClusterNode {
+    pub chitchat_id: ChitchatId,
+    pub status: NodeStatus,
+    /// Services enabled on the node: indexer, searcher, metastore,
+    /// control_plane, janitor.
+    pub roles: Vec<String>,
+    pub grpc_advertise_addr: Option<String>,
+    pub key_values: BTreeMap<String, String>,
+}
+
+impl ClusterNode {
+    /// The gossiped Quickwit version, when the node publishes one.
+    pub fn version(&self) -> Option<&str> {
+        ["build.version", "version"]
+            .iter()
+            .find_map(|key| self.key_values.get(*key))
+            .map(String::as_str)
+    }
+}
+
+/// One indexing pipeline as gossiped by the indexer running it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexingPipeline {
+    pub node_id: String,
+    /// Generation of the node; it changes when the node restarts.
+    pub node_generation: u64,
+    pub index_id: String,
+    pub source_id: String,
+    /// Only gossiped by newer nodes.
+    pub pipeline_uid: Option<String>,
+    /// Position among the pipelines of the same source on the node.
+    pub ordinal: usize,
+}
+
+/// Indexing pipelines a node runs for one source.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexingTask {
+    pub node_id: String,
+    pub index_id: String,
+    pub source_id: String,
+    pub num_pipelines: usize,
+}
+
+impl Cluster {
+    /// Every known member, ready ones first. Dead nodes keep whatever state
+    /// was last gossiped for them.
+    pub fn nodes(&self) -> Vec<ClusterNode> {
+        let snapshots = &self.chitchat_state_snapshot.node_state_snapshots;
+        let mut nodes: Vec<ClusterNode> = Vec::new();
+
+        let members = self
+            .ready_nodes
+            .iter()
+            .map(|id| (id, NodeStatus::Ready))
+            .chain(self.live_nodes.iter().map(|id| (id, NodeStatus::Live)))
+            .chain(self.dead_nodes.iter().map(|id| (id, NodeStatus::Dead)))
+            .chain(
+                snapshots
+                    .iter()
+                    .map(|snapshot| (&snapshot.chitchat_id, NodeStatus::Live)),
+            );
+
+        for (chitchat_id, status) in members {
+            if nodes.iter().any(|node| node.chitchat_id == *chitchat_id) {
+                continue;
+            }
+            let key_values: BTreeMap<String, String> = snapshots
+                .iter()
+                .find(|snapshot| snapshot.chitchat_id == *chitchat_id)
+                .map(|snapshot| {
+                    snapshot
+                        .node_state
+                        .key_values
+                        .iter()
+                        .map(|(key, versioned)| (key.clone(), versioned.value.clone()))
+                        .collect()
+                })
+                .unwrap_or_default();
+            let roles = key_values
+                .get("enabled_services")
+                .map(|services| {
+                    services
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|service| !service.is_empty())
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default();
+            // Older nodes only gossip readiness through this key.
+            let status = match (status, key_values.get("readiness").map(String::as_str)) {
+                (NodeStatus::Live, Some("READY")) => NodeStatus::Ready,
+                (status, _) => status,
+            };
+
+            nodes.push(ClusterNode {
+                chitchat_id: chitchat_id.clone(),
+                status,
+                roles,
+                grpc_advertise_addr: key_values.get("grpc_advertise_addr").cloned(),
+                key_values,
+            });
+        }
+
+        nodes
+    }
+
+    /// Indexing pipelines gossiped by indexers. Older nodes publish one
+    /// `indexing_task:{index_uid}:{source_id}` key holding a pipeline count,
+    /// newer ones one `indexer.task:{pipeline_uid}` key per pipeline whose
+    /// value starts with `{index_uid}:{source_id}`.
+    pub fn indexing_pipelines(&self) -> Vec<IndexingPipeline> {
+        let mut pipelines: Vec<IndexingPipeline> = Vec::new();
+
+        for snapshot in &self.chitchat_state_snapshot.node_state_snapshots {
+            let chitchat_id = &snapshot.chitchat_id;
+            for (key, versioned) in &snapshot.node_state.key_values {
+                let (index_uid_and_source, pipeline_uid, num_pipelines) =
+                    if let Some(rest) = key.strip_prefix("indexing_task:") {
+                        (rest, None, versioned.value.parse().unwrap_or(1))
+                    } else if let Some(pipeline_uid) = key.strip_prefix("indexer.task:") {
+                        (versioned.value.as_str(), Some(pipeline_uid.to_string()), 1)
+                    } else {
+                        continue;
+                    };
+
+                // index_id:ulid:source_id[:shards]
+                let mut parts = index_uid_and_source.splitn(4, ':');
+                let (Some(index_id), Some(_ulid), Some(source_id)) =
+                    (parts.next(), parts.next(), parts.next())
+                else {
+                    continue;
+                };
+
+                for _ in 0..num_pipelines {
+                    let ordinal = pipelines
+                        .iter()
+                        .filter(|pipeline| {
+                            pipeline.node_id == chitchat_id.node_id
+                                && pipeline.index_id == index_id
+                                && pipeline.source_id == source_id
+                        })
+                        .count();
+                    pipelines.push(IndexingPipeline {
+                        node_id: chitchat_id.node_id.clone(),
+                        node_generation: chitchat_id.generation_id,
+                        index_id: index_id.to_string(),
+                        source_id: source_id.to_string(),
+                        pipeline_uid: pipeline_uid.clone(),
+                        ordinal,
+                    });
+                }
+            }
+        }
+
+        pipelines
+    }
+
+    /// Indexing pipelines grouped by node and source.
+    pub fn indexing_tasks(&self) -> Vec<IndexingTask> {
+        let mut tasks: Vec<IndexingTask> = Vec::new();
+
+        for pipeline in self.indexing_pipelines() {
+            match tasks.iter_mut().find(|task| {
+                task.node_id == pipeline.node_id
+                    && task.index_id == pipeline.index_id
+                    && task.source_id == pipeline.source_id
+            }) {
+                Some(task) => task.num_pipelines += 1,
+                None => tasks.push(IndexingTask {
+                    node_id: pipeline.node_id,
+                    index_id: pipeline.index_id,
+                    source_id: pipeline.source_id,
+                    num_pipelines: 1,
+                }),
+            }
+        }
+
+        tasks
+    }
+}
+
+/// Response of `/api/v1/version`, describing the node that answered.
+#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct VersionInfo {
+    pub build: BuildInfo,
+}
+
+#[derive(serde::Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct BuildInfo {
+    #[serde(default)]
+    pub version: String,
+    #[serde(default)]
+    pub commit_short_hash: String,
+    #[serde(default)]
+    pub build_date: String,
+}
+
+impl BuildInfo {
+    /// Whether the release is `major.minor` or later. Versions that do not
+    /// parse, like nightly builds, count as recent.
+    pub fn is_at_least(&self, major: u64, minor: u64) -> bool {
+        let mut parts = self
+            .version
+            .trim_start_matches('v')
+            .split(['.', '-'])
+            .map(|part| part.parse::<u64>());
+        match (parts.next(), parts.next()) {
+            (Some(Ok(found_major)), Some(Ok(found_minor))) => {
+                (found_major, found_minor) >= (major, minor)
+            }
+            _ => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HealthStatus {
+    Healthy,
+    NotReady,
+    /// Not live, or reported dead by the cluster.
+    Down,
+    /// No endpoint answered the browser, which may only mean the node's
+    /// address is not reachable from here or CORS is not allowed.
+    Unreachable,
+}
+
+impl HealthStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HealthStatus::Healthy => "Healthy",
+            HealthStatus::NotReady => "Not ready",
+            HealthStatus::Down => "Down",
+            HealthStatus::Unreachable => "Unreachable from browser",
+        }
+    }
+}
+
+/// Result of probing one node's health endpoints; `None` means the endpoint
+/// did not answer.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct NodeHealth {
+    pub live: Option<bool>,
+    pub ready: Option<bool>,
+    pub version: Option<String>,
+}
+
+impl NodeHealth {
+    pub fn status(&self) -> HealthStatus {
+        match (self.live, self.ready) {
+            (Some(true), Some(true)) => HealthStatus::Healthy,
+            (Some(true), _) => HealthStatus::NotReady,
+            (None, None) if self.version.is_none() => HealthStatus::Unreachable,
+            _ => HealthStatus::Down,
+        }
+    }
 }
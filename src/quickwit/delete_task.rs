@@ -0,0 +1,24 @@
+#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct DeleteTask {
+    pub create_timestamp: i64,
+    pub opstamp: u64,
+    pub delete_query: DeleteQuery,
+}
+
+#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct DeleteQuery {
+    pub start_timestamp: Option<i64>,
+    pub end_timestamp: Option<i64>,
+    /// The query as a serialized query AST.
+    #[serde(default)]
+    pub query_ast: String,
+}
+
+#[derive(serde::Serialize, Debug, Clone, PartialEq)]
+pub struct DeleteQueryRequest {
+    pub query: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_timestamp: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_timestamp: Option<i64>,
+}
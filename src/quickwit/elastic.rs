@@ -0,0 +1,143 @@
+use crate::quickwit::query::QueryResponse;
+
+#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct ElasticSearchResponse {
+    #[serde(default)]
+    pub took: u64,
+    #[serde(default)]
+    pub hits: ElasticHits,
+    pub error: Option<serde_json::Value>,
+}
+
+#[derive(serde::Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct ElasticHits {
+    pub total: Option<ElasticTotal>,
+    pub hits: Vec<ElasticHit>,
+}
+
+#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct ElasticTotal {
+    pub value: u64,
+    pub relation: String,
+}
+
+#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct ElasticHit {
+    #[serde(rename = "_index")]
+    pub index: String,
+    #[serde(rename = "_source", default)]
+    pub source: serde_json::Value,
+}
+
+#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct ElasticMultiSearchResponse {
+    pub responses: Vec<ElasticSearchResponse>,
+}
+
+/// A search written in Elasticsearch query DSL. A single JSON object goes to
+/// `_search`; header/body line pairs in `_msearch` NDJSON go to `_msearch`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ElasticRequest {
+    Search(serde_json::Value),
+    MultiSearch(Vec<(serde_json::Value, serde_json::Value)>),
+}
+
+impl ElasticRequest {
+    pub fn parse(text: &str) -> anyhow::Result<Self> {
+        if let Ok(body) = serde_json::from_str::<serde_json::Value>(text) {
+            return match body {
+                serde_json::Value::Object(_) => Ok(ElasticRequest::Search(body)),
+                _ => Err(anyhow::anyhow!("search body must be a JSON object")),
+            };
+        }
+
+        let lines = text
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .enumerate()
+            .map(|(i, line)| {
+                serde_json::from_str::<serde_json::Value>(line)
+                    .map_err(|e| anyhow::anyhow!("line {}: {e}", i + 1))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        if lines.is_empty() || lines.len() % 2 != 0 {
+            return Err(anyhow::anyhow!(
+                "expected a JSON object or _msearch header/body line pairs"
+            ));
+        }
+
+        Ok(ElasticRequest::MultiSearch(
+            lines
+                .chunks(2)
+                .map(|pair| (pair[0].clone(), pair[1].clone()))
+                .collect(),
+        ))
+    }
+
+    /// Serializes a multi search as NDJSON, targeting `default_index` from
+    /// headers that don't name one.
+    pub fn to_ndjson(
+        searches: &[(serde_json::Value, serde_json::Value)],
+        default_index: &str,
+    ) -> String {
+        let mut ndjson = String::new();
+        for (header, body) in searches {
+            let mut header = header.clone();
+            if header.get("index").is_none() {
+                header["index"] = serde_json::json!(default_index);
+            }
+            ndjson.push_str(&format!("{header}\n{body}\n"));
+        }
+        ndjson
+    }
+}
+
+impl ElasticSearchResponse {
+    pub fn into_result(self) -> anyhow::Result<Self> {
+        match self.error {
+            Some(error) => Err(anyhow::anyhow!("{error}")),
+            None => Ok(self),
+        }
+    }
+}
+
+impl From<ElasticSearchResponse> for QueryResponse {
+    fn from(response: ElasticSearchResponse) -> Self {
+        let num_hits = response
+            .hits
+            .total
+            .map(|total| total.value as i64)
+            .unwrap_or(response.hits.hits.len() as i64);
+        let (hits, hit_indexes) = response
+            .hits
+            .hits
+            .into_iter()
+            .map(|hit| (hit.source, hit.index))
+            .unzip();
+
+        QueryResponse {
+            elapsed_time_micros: response.took as f64 * 1000.0,
+            hits,
+            num_hits,
+            hit_indexes,
+        }
+    }
+}
+
+impl From<ElasticMultiSearchResponse> for QueryResponse {
+    fn from(response: ElasticMultiSearchResponse) -> Self {
+        response
+            .responses
+            .into_iter()
+            .map(QueryResponse::from)
+            .fold(QueryResponse::default(), |mut merged, next| {
+                merged.elapsed_time_micros =
+                    merged.elapsed_time_micros.max(next.elapsed_time_micros);
+                merged.num_hits += next.num_hits;
+                merged.hits.extend(next.hits);
+                merged.hit_indexes.extend(next.hit_indexes);
+                merged
+            })
+    }
+}
@@ -0,0 +1,69 @@
+use super::RawResponse;
+use futures::future::{FutureExt, LocalBoxFuture, Shared};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use web_sys::{AbortController, AbortSignal};
+
+type SharedResponse = Shared<LocalBoxFuture<'static, Result<RawResponse, String>>>;
+
+thread_local! {
+    /// GETs currently awaiting a response, keyed by URL.
+    static PENDING_GETS: RefCell<HashMap<String, SharedResponse>> = RefCell::new(HashMap::new());
+    /// Controller of the latest request started in each slot.
+    static LATEST: RefCell<HashMap<&'static str, AbortController>> = RefCell::new(HashMap::new());
+}
+
+/// Error of a request aborted because a newer one superseded it.
+#[derive(Debug)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("request cancelled")
+    }
+}
+
+impl std::error::Error for Cancelled {}
+
+pub fn is_cancelled(error: &anyhow::Error) -> bool {
+    error.is::<Cancelled>()
+}
+
+/// Aborts the request previously started in `slot` and returns the signal
+/// for the one about to replace it.
+pub fn supersede(slot: &'static str) -> AbortSignal {
+    let controller = AbortController::new().expect("AbortController is supported");
+    let signal = controller.signal();
+    LATEST.with(|latest| {
+        if let Some(previous) = latest.borrow_mut().insert(slot, controller) {
+            previous.abort();
+        }
+    });
+    signal
+}
+
+/// Joins an identical GET already in flight, or starts one with `send`.
+pub(super) async fn dedup_get(
+    url: &str,
+    send: impl FnOnce() -> LocalBoxFuture<'static, Result<RawResponse, String>>,
+) -> anyhow::Result<RawResponse> {
+    let pending = PENDING_GETS.with(|pending| {
+        pending
+            .borrow_mut()
+            .entry(url.to_string())
+            .or_insert_with(|| {
+                let url = url.to_string();
+                let response = send();
+                async move {
+                    let result = response.await;
+                    PENDING_GETS.with(|pending| pending.borrow_mut().remove(&url));
+                    result
+                }
+                .boxed_local()
+                .shared()
+            })
+            .clone()
+    });
+    pending.await.map_err(|e| anyhow::anyhow!(e))
+}
@@ -5,26 +5,233 @@ pub struct Index {
     pub sources: Vec<Source>,
 }
 
-#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+/// Index config version written by the create-index form.
+pub const INDEX_CONFIG_VERSION: &str = "0.8";
+
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq)]
 pub struct IndexConfig {
+    #[serde(default)]
+    pub version: String,
     pub index_id: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub index_uri: String,
     pub doc_mapping: DocMapping,
+    #[serde(default)]
+    pub indexing_settings: IndexingSettings,
+    #[serde(default)]
+    pub search_settings: SearchSettings,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retention: Option<RetentionPolicy>,
+    /// Settings this UI doesn't model, kept so updates don't drop them.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq, Default)]
+pub struct IndexingSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit_timeout_secs: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub split_num_docs_target: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merge_policy: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<serde_json::Value>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq, Default)]
+pub struct SearchSettings {
+    #[serde(default)]
+    pub default_search_fields: Vec<String>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq)]
+pub struct RetentionPolicy {
+    pub period: String,
+    pub schedule: String,
+}
+
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq, Default)]
 pub struct DocMapping {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
     pub field_mappings: Vec<FieldMapping>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub timestamp_field: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tag_fields: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store_source: Option<bool>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+pub const FIELD_TYPES: [&str; 9] = [
+    "text", "i64", "u64", "f64", "bool", "datetime", "ip", "bytes", "json",
+];
+
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq, Default)]
 pub struct FieldMapping {
     pub name: String,
     pub r#type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indexed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stored: Option<bool>,
+    /// Either a bool or, for text fields, an object naming a normalizer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fast: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tokenizer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub record: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_formats: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fast_precision: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub field_mappings: Vec<FieldMapping>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+impl FieldMapping {
+    pub fn is_fast(&self) -> bool {
+        match &self.fast {
+            Some(serde_json::Value::Bool(fast)) => *fast,
+            Some(serde_json::Value::Object(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Finds a field by its dotted path, descending into `object` mappings. A
+/// path inside a `json` field resolves to that field.
+pub fn find_field<'a>(mappings: &'a [FieldMapping], path: &str) -> Option<&'a FieldMapping> {
+    if let Some(field) = mappings.iter().find(|field| field.name == path) {
+        return Some(field);
+    }
+    let (head, rest) = path.split_once('.')?;
+    let field = mappings.iter().find(|field| field.name == head)?;
+    match field.r#type.as_str() {
+        "object" => find_field(&field.field_mappings, rest),
+        "json" => Some(field),
+        _ => None,
+    }
+}
+
+const HUMANTIME_UNITS: [&str; 30] = [
+    "nsec", "ns", "usec", "us", "msec", "ms", "seconds", "second", "sec", "s", "minutes", "minute",
+    "min", "m", "hours", "hour", "hr", "h", "days", "day", "d", "weeks", "week", "w", "months",
+    "month", "M", "years", "year", "y",
+];
+
+/// Whether Quickwit would parse the period, which it does with `humantime`:
+/// one or more `<number><unit>` terms, spaces optional, e.g. `30d` or
+/// `1 day 12 hours`.
+pub fn is_humantime_duration(period: &str) -> bool {
+    let mut rest = period.trim();
+    if rest.is_empty() {
+        return false;
+    }
+    while !rest.is_empty() {
+        let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        if digits == 0 {
+            return false;
+        }
+        rest = rest[digits..].trim_start();
+        let letters = rest.len()
+            - rest
+                .trim_start_matches(|c: char| c.is_ascii_alphabetic())
+                .len();
+        let unit = &rest[..letters];
+        if !HUMANTIME_UNITS.contains(&unit) {
+            return false;
+        }
+        rest = rest[letters..].trim_start();
+    }
+    true
+}
+
+/// Same rules Quickwit applies to index ids.
+pub fn is_valid_index_id(index_id: &str) -> bool {
+    let mut chars = index_id.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        && (3..=255).contains(&index_id.len())
+}
+
+impl IndexConfig {
+    /// Checks the config before it is sent, returning one message per problem.
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+
+        if !is_valid_index_id(&self.index_id) {
+            errors.push(
+                "Index id must be 3-255 characters, start with a letter and contain only letters, digits, '-', '_' or '.'"
+                    .to_string(),
+            );
+        }
+
+        let mappings = &self.doc_mapping.field_mappings;
+        if mappings.is_empty() && self.doc_mapping.mode.as_deref() != Some("dynamic") {
+            errors.push("Add at least one field or use the dynamic mode".to_string());
+        }
+        for (i, field) in mappings.iter().enumerate() {
+            if field.name.trim().is_empty() {
+                errors.push(format!("Field #{} has no name", i + 1));
+            } else if mappings[..i].iter().any(|other| other.name == field.name) {
+                errors.push(format!("Field '{}' is defined twice", field.name));
+            }
+        }
+
+        let find = |path: &str| find_field(mappings, path);
+        let timestamp_field = &self.doc_mapping.timestamp_field;
+        if !timestamp_field.is_empty() {
+            match find(timestamp_field) {
+                Some(field) if field.r#type == "datetime" && field.is_fast() => {}
+                Some(_) => errors.push(format!(
+                    "Timestamp field '{timestamp_field}' must be a fast datetime field"
+                )),
+                None => errors.push(format!("Timestamp field '{timestamp_field}' is not mapped")),
+            }
+        }
+
+        for name in &self.search_settings.default_search_fields {
+            if self.doc_mapping.mode.as_deref() != Some("dynamic") && find(name).is_none() {
+                errors.push(format!("Default search field '{name}' is not mapped"));
+            }
+        }
+
+        if self.indexing_settings.commit_timeout_secs == Some(0) {
+            errors.push("Commit timeout must be greater than zero".to_string());
+        }
+
+        if let Some(retention) = &self.retention {
+            if timestamp_field.is_empty() {
+                errors.push("Retention requires a timestamp field".to_string());
+            }
+            if !is_humantime_duration(&retention.period) {
+                errors.push(
+                    "Retention period must be a duration like '30 days' or '1d 12h'".to_string(),
+                );
+            }
+        }
+
+        errors
+    }
+}
+
+/// Source config version written by the add-source form.
+pub const SOURCE_CONFIG_VERSION: &str = "0.8";
+
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, PartialEq)]
 pub struct Source {
     pub version: String,
     pub source_id: String,
@@ -32,6 +239,15 @@ pub struct Source {
     pub enabled: bool,
     pub source_type: String,
     pub input_format: String,
+    #[serde(default)]
+    pub params: serde_json::Value,
+}
+
+impl Source {
+    /// Sources Quickwit creates and manages itself, like `_ingest-api-source`.
+    pub fn is_internal(&self) -> bool {
+        self.source_id.starts_with('_')
+    }
 }
 
 #[derive(serde::Deserialize, Debug, Clone, PartialEq)]
@@ -40,18 +256,87 @@ pub struct IndexDescription {
     pub size_published_docs_uncompressed: u64,
     pub num_published_splits: u32,
     pub size_published_splits: u64,
+    pub min_timestamp: Option<i64>,
+    pub max_timestamp: Option<i64>,
 }
 
-#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq)]
 pub enum SplitState {
     Staged,
     Published,
     MarkedForDeletion,
 }
 
+impl SplitState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SplitState::Staged => "Staged",
+            SplitState::Published => "Published",
+            SplitState::MarkedForDeletion => "Marked for deletion",
+        }
+    }
+}
+
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub struct Range<T> {
+    pub start: T,
+    pub end: T,
+}
+
+#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SplitMaturity {
+    Mature,
+    Immature { maturation_period_millis: u64 },
+}
+
 #[derive(serde::Deserialize, Debug, Clone, PartialEq)]
 pub struct Split {
     pub split_state: SplitState,
+    pub split_id: String,
+    #[serde(default)]
+    pub source_id: String,
+    #[serde(default)]
+    pub node_id: String,
+    pub num_docs: u64,
+    pub uncompressed_docs_size_in_bytes: u64,
+    #[serde(default)]
+    pub footer_offsets: Range<u64>,
+    /// Range of the timestamp field covered by the split, in seconds. Missing
+    /// for indexes without a timestamp field.
+    #[serde(default, deserialize_with = "lenient")]
+    pub time_range: Option<Range<i64>>,
+    #[serde(default)]
+    pub create_timestamp: i64,
+    #[serde(default)]
+    pub update_timestamp: i64,
+    pub publish_timestamp: Option<i64>,
+    /// Missing, or in another shape, on older Quickwit versions.
+    #[serde(default, deserialize_with = "lenient")]
+    pub maturity: Option<SplitMaturity>,
+    #[serde(default)]
+    pub delete_opstamp: u64,
+    #[serde(default)]
+    pub num_merge_ops: u64,
+}
+
+/// Deserializes an optional field, treating a value of an unexpected shape as
+/// missing so one odd split does not fail the whole listing.
+fn lenient<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::de::DeserializeOwned,
+{
+    let value = <serde_json::Value as serde::Deserialize>::deserialize(deserializer)?;
+    Ok(serde_json::from_value(value).ok())
+}
+
+impl Split {
+    pub fn footer_size(&self) -> u64 {
+        self.footer_offsets
+            .end
+            .saturating_sub(self.footer_offsets.start)
+    }
 }
 
 #[derive(serde::Deserialize, Debug, Clone, PartialEq)]
@@ -59,3 +344,99 @@ pub struct ListSplitsResponse {
     pub splits: Vec<Split>,
     pub total_count: Option<u64>,
 }
+
+/// A file removed, or that would be removed, by deleting an index.
+#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct FileEntry {
+    pub file_name: String,
+    pub file_size_bytes: u64,
+}
+
+/// Whether an index id expression targets more than one index, either as a
+/// comma-separated list or through a `*` pattern.
+pub fn is_multi_index(index_expr: &str) -> bool {
+    index_expr.contains([',', '*'])
+}
+
+/// Matches an index id against a Quickwit index id pattern, where `*` stands
+/// for any run of characters.
+pub fn matches_index_pattern(pattern: &str, index_id: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = index_id.strip_prefix(first) else {
+        return false;
+    };
+
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        return rest.is_empty();
+    };
+
+    for part in middle {
+        match rest.find(part) {
+            Some(pos) => rest = &rest[pos + part.len()..],
+            None => return false,
+        }
+    }
+
+    rest.len() >= last.len() && rest.ends_with(last)
+}
+
+/// Whether an index id is selected by a comma-separated list of ids and patterns.
+pub fn matches_index_expr(index_expr: &str, index_id: &str) -> bool {
+    index_expr
+        .split(',')
+        .any(|pattern| matches_index_pattern(pattern.trim(), index_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_index_pattern_without_wildcards_is_exact() {
+        assert!(matches_index_pattern("logs", "logs"));
+        assert!(!matches_index_pattern("logs", "logs-2024"));
+        assert!(!matches_index_pattern("logs", "log"));
+    }
+
+    #[test]
+    fn matches_index_pattern_with_wildcards() {
+        assert!(matches_index_pattern("logs-*", "logs-2024"));
+        assert!(matches_index_pattern("logs-*", "logs-"));
+        assert!(matches_index_pattern("*-prod", "api-prod"));
+        assert!(matches_index_pattern("*", "anything"));
+        assert!(matches_index_pattern("otel-*-v*", "otel-logs-v2"));
+        assert!(!matches_index_pattern("otel-*-v*", "otel-logs"));
+        assert!(!matches_index_pattern("a*a", "a"));
+    }
+
+    #[test]
+    fn matches_index_expr_accepts_any_listed_pattern() {
+        assert!(matches_index_expr("traces, logs-*", "logs-api"));
+        assert!(matches_index_expr("traces, logs-*", "traces"));
+        assert!(!matches_index_expr("traces, logs-*", "metrics"));
+        assert!(is_multi_index("a,b"));
+        assert!(is_multi_index("logs-*"));
+        assert!(!is_multi_index("logs"));
+    }
+
+    #[test]
+    fn is_humantime_duration_accepts_quickwit_periods() {
+        assert!(is_humantime_duration("30d"));
+        assert!(is_humantime_duration("90 days"));
+        assert!(is_humantime_duration("1 day 12 hours"));
+        assert!(is_humantime_duration("1h30min"));
+        assert!(is_humantime_duration(" 6M "));
+    }
+
+    #[test]
+    fn is_humantime_duration_rejects_malformed_periods() {
+        assert!(!is_humantime_duration(""));
+        assert!(!is_humantime_duration("30"));
+        assert!(!is_humantime_duration("d"));
+        assert!(!is_humantime_duration("30 fortnights"));
+        assert!(!is_humantime_duration("1.5h"));
+        assert!(!is_humantime_duration("-1d"));
+    }
+}
@@ -1,4 +1,68 @@
-#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
-pub struct Indexing {
-    pub num_staged_splits: Option<i64>,
+use crate::quickwit::cluster::IndexingTask;
+use crate::quickwit::metrics::{self, Sample};
+
+pub const PROCESSED_DOCS: &str = "quickwit_indexing_processed_docs_total";
+pub const PROCESSED_BYTES: &str = "quickwit_indexing_processed_bytes";
+
+/// Indexing statistics for a single index, combining the indexers' `/metrics`
+/// counters with the pipelines gossiped in the cluster state.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct IndexIndexingStats {
+    pub num_valid_docs: u64,
+    pub num_invalid_docs: u64,
+    pub total_bytes_processed: u64,
+    pub tasks: Vec<IndexingTask>,
+    /// Indexers whose counters could not be read and are left out.
+    pub unreached_nodes: Vec<String>,
+}
+
+impl IndexIndexingStats {
+    pub fn new(index_id: &str, samples: &[Sample], tasks: Vec<IndexingTask>) -> Self {
+        let for_index = |sample: &Sample| sample.label("index") == Some(index_id);
+        let is_valid = |sample: &Sample| sample.label("docs_processed_status") == Some("valid");
+
+        IndexIndexingStats {
+            num_valid_docs: metrics::sum(samples, PROCESSED_DOCS, |s| for_index(s) && is_valid(s))
+                as u64,
+            num_invalid_docs: metrics::sum(samples, PROCESSED_DOCS, |s| {
+                for_index(s) && !is_valid(s)
+            }) as u64,
+            total_bytes_processed: metrics::sum(samples, PROCESSED_BYTES, for_index) as u64,
+            tasks: tasks
+                .into_iter()
+                .filter(|task| task.index_id == index_id)
+                .collect(),
+            unreached_nodes: Vec::new(),
+        }
+    }
+
+    pub fn num_docs_processed(&self) -> u64 {
+        self.num_valid_docs + self.num_invalid_docs
+    }
+
+    pub fn num_pipelines(&self) -> usize {
+        self.tasks.iter().map(|task| task.num_pipelines).sum()
+    }
+
+    /// Pipeline count per source, summed across nodes.
+    pub fn pipelines_by_source(&self) -> Vec<(String, usize)> {
+        let mut sources: Vec<(String, usize)> = Vec::new();
+        for task in &self.tasks {
+            match sources.iter_mut().find(|(id, _)| *id == task.source_id) {
+                Some((_, count)) => *count += task.num_pipelines,
+                None => sources.push((task.source_id.clone(), task.num_pipelines)),
+            }
+        }
+        sources
+    }
+}
+
+/// Pipeline counters reported by a node's indexing service.
+#[derive(serde::Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(default)]
+pub struct IndexingServiceCounters {
+    pub num_running_pipelines: usize,
+    pub num_successful_pipelines: usize,
+    pub num_failed_pipelines: usize,
+    pub num_running_merge_pipelines: usize,
 }
@@ -0,0 +1,267 @@
+use crate::quickwit::index::{DocMapping, FieldMapping};
+use serde_json::Value;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum CommitMode {
+    #[default]
+    Auto,
+    WaitFor,
+    Force,
+}
+
+impl fmt::Display for CommitMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl CommitMode {
+    pub const ALL: [CommitMode; 3] = [CommitMode::Auto, CommitMode::WaitFor, CommitMode::Force];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CommitMode::Auto => "auto",
+            CommitMode::WaitFor => "wait_for",
+            CommitMode::Force => "force",
+        }
+    }
+}
+
+#[derive(serde::Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct IngestResponse {
+    #[serde(default)]
+    pub num_docs_for_processing: u64,
+    pub num_ingested_docs: Option<u64>,
+    pub num_rejected_docs: Option<u64>,
+}
+
+/// Whether Quickwit turns strings like `"42"` or `"true"` into the field's
+/// type, which it does unless the mapping sets `coerce: false`.
+fn coerces(field: &FieldMapping) -> bool {
+    field
+        .extra
+        .get("coerce")
+        .and_then(Value::as_bool)
+        .unwrap_or(true)
+}
+
+fn type_matches(field_type: &str, value: &Value, coerce: bool) -> bool {
+    if let Some(element_type) = field_type
+        .strip_prefix("array<")
+        .and_then(|rest| rest.strip_suffix('>'))
+    {
+        return match value {
+            Value::Array(values) => values
+                .iter()
+                .all(|value| type_matches(element_type, value, coerce)),
+            value => type_matches(element_type, value, coerce),
+        };
+    }
+
+    match (field_type, value) {
+        (_, Value::Null) => true,
+        ("text" | "bytes", Value::String(_)) => true,
+        ("i64", Value::Number(n)) => n.is_i64(),
+        ("u64", Value::Number(n)) => n.is_u64(),
+        ("f64", Value::Number(_)) => true,
+        ("bool", Value::Bool(_)) => true,
+        ("i64", Value::String(s)) if coerce => s.trim().parse::<i64>().is_ok(),
+        ("u64", Value::String(s)) if coerce => s.trim().parse::<u64>().is_ok(),
+        ("f64", Value::String(s)) if coerce => s.trim().parse::<f64>().is_ok(),
+        ("bool", Value::String(s)) if coerce => matches!(s.as_str(), "true" | "false"),
+        ("datetime", Value::String(_) | Value::Number(_)) => true,
+        ("ip", Value::String(ip)) => ip.parse::<std::net::IpAddr>().is_ok(),
+        ("json" | "object", Value::Object(_)) => true,
+        _ => false,
+    }
+}
+
+fn validate_fields(
+    prefix: &str,
+    mappings: &[FieldMapping],
+    object: &serde_json::Map<String, Value>,
+    strict: bool,
+    errors: &mut Vec<String>,
+) {
+    for field in mappings {
+        let path = format!("{prefix}{}", field.name);
+        let Some(value) = object.get(&field.name) else {
+            continue;
+        };
+        if !type_matches(&field.r#type, value, coerces(field)) {
+            errors.push(format!("'{path}' should be {}, got {value}", field.r#type));
+        } else if field.r#type == "object"
+            && let Value::Object(inner) = value
+        {
+            validate_fields(
+                &format!("{path}."),
+                &field.field_mappings,
+                inner,
+                strict,
+                errors,
+            );
+        }
+    }
+
+    if strict {
+        for key in object.keys() {
+            if !mappings.iter().any(|field| field.name == *key) {
+                errors.push(format!(
+                    "'{prefix}{key}' is not mapped and the mode is strict"
+                ));
+            }
+        }
+    }
+}
+
+/// Checks one document against the doc mapping the way Quickwit would reject
+/// it: wrong types, a missing timestamp and unmapped fields in strict mode.
+pub fn validate_document(doc_mapping: &DocMapping, document: &Value) -> Vec<String> {
+    let Value::Object(object) = document else {
+        return vec!["document must be a JSON object".to_string()];
+    };
+
+    let mut errors = Vec::new();
+    let strict = doc_mapping.mode.as_deref() == Some("strict");
+    validate_fields("", &doc_mapping.field_mappings, object, strict, &mut errors);
+
+    let timestamp_field = &doc_mapping.timestamp_field;
+    if !timestamp_field.is_empty()
+        && timestamp_field
+            .split('.')
+            .try_fold(document, |value, key| value.get(key))
+            .is_none_or(Value::is_null)
+    {
+        errors.push(format!("timestamp field '{timestamp_field}' is missing"));
+    }
+
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn doc_mapping(mapping: Value) -> DocMapping {
+        serde_json::from_value(mapping).unwrap()
+    }
+
+    fn logs_mapping() -> DocMapping {
+        doc_mapping(json!({
+            "timestamp_field": "ts",
+            "field_mappings": [
+                { "name": "ts", "type": "datetime" },
+                { "name": "status", "type": "u64" },
+                { "name": "latency", "type": "f64", "coerce": false },
+                { "name": "tags", "type": "array<text>" },
+                { "name": "resource", "type": "object", "field_mappings": [
+                    { "name": "pid", "type": "i64" },
+                ] },
+            ],
+        }))
+    }
+
+    #[test]
+    fn type_matches_plain_types() {
+        assert!(type_matches("text", &json!("a"), true));
+        assert!(type_matches("i64", &json!(-3), true));
+        assert!(!type_matches("u64", &json!(-3), true));
+        assert!(type_matches("f64", &json!(3), true));
+        assert!(type_matches("bool", &json!(false), true));
+        assert!(type_matches("ip", &json!("::1"), true));
+        assert!(!type_matches("ip", &json!("localhost"), true));
+        assert!(type_matches("json", &json!({ "a": 1 }), true));
+        assert!(type_matches("u64", &Value::Null, false));
+        assert!(!type_matches("text", &json!(1), true));
+    }
+
+    #[test]
+    fn type_matches_coerces_strings_unless_disabled() {
+        assert!(type_matches("i64", &json!(" 42 "), true));
+        assert!(type_matches("f64", &json!("1.5"), true));
+        assert!(type_matches("bool", &json!("true"), true));
+        assert!(!type_matches("bool", &json!("yes"), true));
+        assert!(!type_matches("u64", &json!("-1"), true));
+        assert!(!type_matches("i64", &json!("42"), false));
+    }
+
+    #[test]
+    fn type_matches_arrays_check_every_element() {
+        assert!(type_matches("array<i64>", &json!([1, 2]), true));
+        assert!(type_matches("array<i64>", &json!(1), true));
+        assert!(!type_matches("array<i64>", &json!([1, "x"]), true));
+        assert!(!type_matches("i64", &json!([1]), true));
+    }
+
+    #[test]
+    fn validate_document_accepts_a_valid_document() {
+        let document = json!({
+            "ts": "2024-01-01T00:00:00Z",
+            "status": "200",
+            "latency": 0.5,
+            "tags": ["a", "b"],
+            "resource": { "pid": 7 },
+            "unmapped": true,
+        });
+        assert!(validate_document(&logs_mapping(), &document).is_empty());
+    }
+
+    #[test]
+    fn validate_document_reports_type_errors_and_missing_timestamp() {
+        let document = json!({ "latency": "0.5", "tags": [1] });
+        assert_eq!(
+            validate_document(&logs_mapping(), &document),
+            vec![
+                "'latency' should be f64, got \"0.5\"",
+                "'tags' should be array<text>, got [1]",
+                "timestamp field 'ts' is missing",
+            ]
+        );
+        assert_eq!(
+            validate_document(&logs_mapping(), &json!([1])),
+            vec!["document must be a JSON object"]
+        );
+    }
+
+    #[test]
+    fn validate_document_checks_nested_objects() {
+        let document = json!({ "ts": 1, "resource": { "pid": "x" } });
+        assert_eq!(
+            validate_document(&logs_mapping(), &document),
+            vec!["'resource.pid' should be i64, got \"x\""]
+        );
+    }
+
+    #[test]
+    fn validate_document_rejects_unmapped_fields_in_strict_mode() {
+        let mapping = doc_mapping(json!({
+            "mode": "strict",
+            "field_mappings": [
+                { "name": "resource", "type": "object", "field_mappings": [
+                    { "name": "pid", "type": "i64" },
+                ] },
+            ],
+        }));
+        let document = json!({ "extra": 1, "resource": { "pid": 1, "host": "a" } });
+        assert_eq!(
+            validate_document(&mapping, &document),
+            vec![
+                "'resource.host' is not mapped and the mode is strict",
+                "'extra' is not mapped and the mode is strict",
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_document_finds_nested_timestamp_fields() {
+        let mut mapping = logs_mapping();
+        mapping.timestamp_field = "resource.ts".to_string();
+        assert!(validate_document(&mapping, &json!({ "resource": { "ts": 1 } })).is_empty());
+        assert_eq!(
+            validate_document(&mapping, &json!({ "resource": { "ts": null } })),
+            vec!["timestamp field 'resource.ts' is missing"]
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use dioxus::prelude::*;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+const MAX_RECORDED: usize = 500;
+/// Bodies are cut past this size; the recorded size stays exact.
+const MAX_BODY_BYTES: usize = 64 * 1024;
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Requests sent by `QuickwitApi`, oldest first.
+pub static RECORDED: GlobalSignal<VecDeque<RecordedRequest>> = Signal::global(VecDeque::new);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedRequest {
+    pub id: u64,
+    pub method: &'static str,
+    pub url: String,
+    /// `None` when no response arrived.
+    pub status: Option<u16>,
+    pub error: Option<String>,
+    /// Milliseconds since the epoch.
+    pub started_at: i64,
+    pub latency_ms: i64,
+    pub request_body: Option<String>,
+    pub request_size: usize,
+    pub response_body: String,
+    pub response_size: usize,
+}
+
+impl RecordedRequest {
+    pub fn is_error(&self) -> bool {
+        self.status.is_none_or(|status| status >= 400)
+    }
+
+    /// Replays the request with curl; truncated bodies are sent truncated.
+    pub fn to_curl(&self) -> String {
+        let mut command = format!("curl -X{} {}", self.method, super::shell_quote(&self.url));
+        if let Some(body) = &self.request_body {
+            command.push_str(&format!(" --data-binary {}", super::shell_quote(body)));
+        }
+        command
+    }
+}
+
+fn truncate(body: &str) -> String {
+    if body.len() <= MAX_BODY_BYTES {
+        return body.to_string();
+    }
+    let mut end = MAX_BODY_BYTES;
+    while !body.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}…", &body[..end])
+}
+
+pub(super) struct Exchange<'a> {
+    pub method: &'static str,
+    pub url: &'a str,
+    pub request_body: Option<&'a str>,
+    pub started_at: i64,
+    pub finished_at: i64,
+    pub status: Option<u16>,
+    pub response_body: &'a str,
+    pub error: Option<String>,
+}
+
+pub(super) fn record(exchange: Exchange) {
+    // Node configs can hold credentials, which the inspector must not show
+    // when the config viewer hides them.
+    let response_body = if exchange.url.ends_with("/api/v1/config") {
+        super::config::redact_body(exchange.response_body)
+    } else {
+        exchange.response_body.to_string()
+    };
+    let request = RecordedRequest {
+        id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+        method: exchange.method,
+        url: exchange.url.to_string(),
+        status: exchange.status,
+        error: exchange.error,
+        started_at: exchange.started_at,
+        latency_ms: exchange.finished_at - exchange.started_at,
+        request_body: exchange.request_body.map(truncate),
+        request_size: exchange.request_body.map_or(0, str::len),
+        response_body: truncate(&response_body),
+        response_size: exchange.response_body.len(),
+    };
+
+    let mut recorded = RECORDED.write();
+    recorded.push_back(request);
+    while recorded.len() > MAX_RECORDED {
+        recorded.pop_front();
+    }
+}
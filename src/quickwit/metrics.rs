@@ -0,0 +1,393 @@
+use std::collections::BTreeMap;
+
+/// One sample line of the Prometheus text exposition format.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sample {
+    pub name: String,
+    pub labels: BTreeMap<String, String>,
+    pub value: f64,
+}
+
+impl Sample {
+    pub fn label(&self, name: &str) -> Option<&str> {
+        self.labels.get(name).map(String::as_str)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum MetricKind {
+    Counter,
+    Gauge,
+    Histogram,
+    Summary,
+    #[default]
+    Untyped,
+}
+
+impl MetricKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MetricKind::Counter => "counter",
+            MetricKind::Gauge => "gauge",
+            MetricKind::Histogram => "histogram",
+            MetricKind::Summary => "summary",
+            MetricKind::Untyped => "untyped",
+        }
+    }
+}
+
+/// What the `# TYPE` and `# HELP` comments say about a metric.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MetricFamily {
+    pub name: String,
+    pub kind: MetricKind,
+    pub help: String,
+}
+
+impl MetricFamily {
+    /// Whether a sample belongs to the family, including the `_bucket`,
+    /// `_sum` and `_count` series of histograms and summaries.
+    pub fn contains(&self, sample: &Sample) -> bool {
+        match sample.name.strip_prefix(self.name.as_str()) {
+            Some("") => true,
+            Some("_bucket" | "_sum" | "_count") => {
+                matches!(self.kind, MetricKind::Histogram | MetricKind::Summary)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Parses the `# TYPE` and `# HELP` comments of a Prometheus text
+/// exposition, keyed by metric name.
+pub fn parse_families(text: &str) -> BTreeMap<String, MetricFamily> {
+    let mut families: BTreeMap<String, MetricFamily> = BTreeMap::new();
+    for line in text.lines() {
+        let Some(comment) = line.trim().strip_prefix('#') else {
+            continue;
+        };
+        let mut parts = comment.trim_start().splitn(3, ' ');
+        let (Some(keyword), Some(name)) = (parts.next(), parts.next()) else {
+            continue;
+        };
+        let rest = parts.next().unwrap_or_default().trim();
+        let family = families
+            .entry(name.to_string())
+            .or_insert_with(|| MetricFamily {
+                name: name.to_string(),
+                ..Default::default()
+            });
+        match keyword {
+            "TYPE" => {
+                family.kind = match rest {
+                    "counter" => MetricKind::Counter,
+                    "gauge" => MetricKind::Gauge,
+                    "histogram" => MetricKind::Histogram,
+                    "summary" => MetricKind::Summary,
+                    _ => MetricKind::Untyped,
+                }
+            }
+            "HELP" => family.help = rest.replace("\\n", "\n").replace("\\\\", "\\"),
+            _ => {}
+        }
+    }
+    families
+}
+
+/// A whole `/metrics` scrape.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Exposition {
+    pub samples: Vec<Sample>,
+    pub families: BTreeMap<String, MetricFamily>,
+}
+
+pub fn parse_exposition(text: &str) -> Exposition {
+    Exposition {
+        samples: parse(text),
+        families: parse_families(text),
+    }
+}
+
+/// Parses the samples of a Prometheus text exposition, skipping comments
+/// and lines that don't parse.
+pub fn parse(text: &str) -> Vec<Sample> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(parse_sample)
+        .collect()
+}
+
+fn parse_sample(line: &str) -> Option<Sample> {
+    let name_end = line
+        .find(|c: char| c == '{' || c.is_whitespace())
+        .unwrap_or(line.len());
+    let name = line[..name_end].to_string();
+    let mut rest = &line[name_end..];
+
+    let mut labels = BTreeMap::new();
+    if let Some(body) = rest.strip_prefix('{') {
+        let (parsed, remainder) = parse_labels(body)?;
+        labels = parsed;
+        rest = remainder;
+    }
+
+    // An optional timestamp may follow the value.
+    let value = match rest.split_whitespace().next()? {
+        "+Inf" => f64::INFINITY,
+        "-Inf" => f64::NEG_INFINITY,
+        value => value.parse().ok()?,
+    };
+
+    Some(Sample {
+        name,
+        labels,
+        value,
+    })
+}
+
+/// Parses `key="value",...}` and returns the labels along with whatever
+/// follows the closing brace.
+fn parse_labels(mut body: &str) -> Option<(BTreeMap<String, String>, &str)> {
+    let mut labels = BTreeMap::new();
+    loop {
+        body = body.trim_start_matches([',', ' ']);
+        if let Some(rest) = body.strip_prefix('}') {
+            return Some((labels, rest));
+        }
+
+        let (key, rest) = body.split_once('=')?;
+        let mut chars = rest.strip_prefix('"')?.char_indices();
+        let mut value = String::new();
+        let end = loop {
+            match chars.next()? {
+                (i, '"') => break i,
+                (_, '\\') => match chars.next()?.1 {
+                    'n' => value.push('\n'),
+                    c => value.push(c),
+                },
+                (_, c) => value.push(c),
+            }
+        };
+
+        labels.insert(key.trim().to_string(), value);
+        body = &rest[end + 2..];
+    }
+}
+
+/// Sums every sample of `name` whose labels satisfy `filter`.
+pub fn sum(samples: &[Sample], name: &str, filter: impl Fn(&Sample) -> bool) -> f64 {
+    samples
+        .iter()
+        .filter(|sample| sample.name == name && filter(sample))
+        .map(|sample| sample.value)
+        .sum()
+}
+
+/// Cumulative histogram buckets summed over every label set but `le`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Histogram {
+    /// Upper bounds and cumulative counts, sorted by bound.
+    pub buckets: Vec<(f64, f64)>,
+    pub sum: f64,
+    pub count: f64,
+}
+
+impl Histogram {
+    pub fn new(samples: &[Sample], name: &str, filter: impl Fn(&Sample) -> bool) -> Self {
+        let bucket_name = format!("{name}_bucket");
+        let mut buckets: Vec<(f64, f64)> = Vec::new();
+        for sample in samples
+            .iter()
+            .filter(|sample| sample.name == bucket_name && filter(sample))
+        {
+            let Some(le) = sample.label("le").and_then(|le| match le {
+                "+Inf" => Some(f64::INFINITY),
+                le => le.parse::<f64>().ok(),
+            }) else {
+                continue;
+            };
+            match buckets.iter_mut().find(|(bound, _)| *bound == le) {
+                Some((_, count)) => *count += sample.value,
+                None => buckets.push((le, sample.value)),
+            }
+        }
+        buckets.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        Histogram {
+            buckets,
+            sum: sum(samples, &format!("{name}_sum"), &filter),
+            count: sum(samples, &format!("{name}_count"), &filter),
+        }
+    }
+
+    /// Observations recorded since `previous`, for quantiles over a window
+    /// rather than since the process started.
+    pub fn since(&self, previous: &Histogram) -> Histogram {
+        // A lower count means the process restarted and the counters reset.
+        if self.count < previous.count {
+            return self.clone();
+        }
+        let previous_count = |bound: f64| {
+            previous
+                .buckets
+                .iter()
+                .find(|(previous_bound, _)| *previous_bound == bound)
+                .map_or(0.0, |(_, count)| *count)
+        };
+        Histogram {
+            buckets: self
+                .buckets
+                .iter()
+                .map(|(bound, count)| (*bound, (count - previous_count(*bound)).max(0.0)))
+                .collect(),
+            sum: (self.sum - previous.sum).max(0.0),
+            count: (self.count - previous.count).max(0.0),
+        }
+    }
+
+    /// Estimates the `q` quantile by linear interpolation inside the bucket
+    /// holding it, like PromQL's `histogram_quantile`.
+    pub fn quantile(&self, q: f64) -> Option<f64> {
+        let total = self.buckets.last()?.1;
+        if total <= 0.0 {
+            return None;
+        }
+        let rank = q * total;
+        let mut lower = (0.0, 0.0);
+        for &(bound, count) in &self.buckets {
+            if count >= rank {
+                if bound.is_infinite() {
+                    return Some(lower.0);
+                }
+                let in_bucket = count - lower.1;
+                if in_bucket <= 0.0 {
+                    return Some(bound);
+                }
+                return Some(lower.0 + (bound - lower.0) * (rank - lower.1) / in_bucket);
+            }
+            lower = (bound, count);
+        }
+        Some(lower.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCRAPE: &str = r#"# HELP quickwit_search_latency_seconds Search latency.\nIn seconds.
+# TYPE quickwit_search_latency_seconds histogram
+quickwit_search_latency_seconds_bucket{index="logs",le="0.1"} 10
+quickwit_search_latency_seconds_bucket{index="logs",le="0.5"} 30
+quickwit_search_latency_seconds_bucket{index="logs",le="+Inf"} 40
+quickwit_search_latency_seconds_bucket{index="otel",le="0.1"} 0
+quickwit_search_latency_seconds_bucket{index="otel",le="0.5"} 0
+quickwit_search_latency_seconds_bucket{index="otel",le="+Inf"} 0
+quickwit_search_latency_seconds_sum{index="logs"} 12.5
+quickwit_search_latency_seconds_count{index="logs"} 40
+# TYPE quickwit_up gauge
+quickwit_up 1 1700000000000
+quickwit_label_escapes{path="C:\\data",note="say \"hi\"\nbye"} 2
+not a sample
+"#;
+
+    fn histogram(buckets: &[(f64, f64)]) -> Histogram {
+        Histogram {
+            buckets: buckets.to_vec(),
+            sum: 0.0,
+            count: buckets.last().map_or(0.0, |(_, count)| *count),
+        }
+    }
+
+    #[test]
+    fn parse_reads_labels_values_and_timestamps() {
+        let samples = parse(SCRAPE);
+        assert_eq!(samples.len(), 10);
+
+        let bucket = &samples[2];
+        assert_eq!(bucket.name, "quickwit_search_latency_seconds_bucket");
+        assert_eq!(bucket.label("index"), Some("logs"));
+        assert_eq!(bucket.label("le"), Some("+Inf"));
+        assert_eq!(bucket.value, 40.0);
+
+        let up = samples.iter().find(|s| s.name == "quickwit_up").unwrap();
+        assert!(up.labels.is_empty());
+        assert_eq!(up.value, 1.0);
+
+        let escapes = samples.last().unwrap();
+        assert_eq!(escapes.label("path"), Some("C:\\data"));
+        assert_eq!(escapes.label("note"), Some("say \"hi\"\nbye"));
+    }
+
+    #[test]
+    fn parse_reads_infinite_values() {
+        let samples = parse("a +Inf\nb -Inf\nc NaN");
+        assert_eq!(samples[0].value, f64::INFINITY);
+        assert_eq!(samples[1].value, f64::NEG_INFINITY);
+        assert!(samples[2].value.is_nan());
+    }
+
+    #[test]
+    fn parse_families_reads_type_and_help() {
+        let families = parse_families(SCRAPE);
+        let latency = &families["quickwit_search_latency_seconds"];
+        assert_eq!(latency.kind, MetricKind::Histogram);
+        assert_eq!(latency.help, "Search latency.\nIn seconds.");
+        assert_eq!(families["quickwit_up"].kind, MetricKind::Gauge);
+
+        let samples = parse(SCRAPE);
+        assert!(latency.contains(&samples[0]));
+        assert!(!families["quickwit_up"].contains(&samples[0]));
+    }
+
+    #[test]
+    fn histogram_sums_label_sets() {
+        let samples = parse(SCRAPE);
+        let latency = Histogram::new(&samples, "quickwit_search_latency_seconds", |_| true);
+        assert_eq!(
+            latency.buckets,
+            vec![(0.1, 10.0), (0.5, 30.0), (f64::INFINITY, 40.0)]
+        );
+        assert_eq!(latency.sum, 12.5);
+        assert_eq!(latency.count, 40.0);
+
+        let otel = Histogram::new(&samples, "quickwit_search_latency_seconds", |sample| {
+            sample.label("index") == Some("otel")
+        });
+        assert_eq!(otel.quantile(0.5), None);
+    }
+
+    #[test]
+    fn quantile_interpolates_inside_buckets() {
+        let latency = histogram(&[(0.1, 10.0), (0.5, 30.0), (f64::INFINITY, 40.0)]);
+        assert!((latency.quantile(0.5).unwrap() - 0.3).abs() < 1e-9);
+        assert!((latency.quantile(0.2).unwrap() - 0.08).abs() < 1e-9);
+    }
+
+    #[test]
+    fn quantile_in_inf_bucket_is_the_highest_finite_bound() {
+        let latency = histogram(&[(0.1, 10.0), (0.5, 30.0), (f64::INFINITY, 40.0)]);
+        assert_eq!(latency.quantile(0.95), Some(0.5));
+        assert_eq!(histogram(&[]).quantile(0.5), None);
+    }
+
+    #[test]
+    fn since_subtracts_previous_counts() {
+        let previous = histogram(&[(0.1, 10.0), (0.5, 30.0), (f64::INFINITY, 40.0)]);
+        let current = histogram(&[(0.1, 15.0), (0.5, 40.0), (f64::INFINITY, 60.0)]);
+        let window = current.since(&previous);
+        assert_eq!(
+            window.buckets,
+            vec![(0.1, 5.0), (0.5, 10.0), (f64::INFINITY, 20.0)]
+        );
+        assert_eq!(window.count, 20.0);
+    }
+
+    #[test]
+    fn since_after_counter_reset_keeps_current_counts() {
+        let previous = histogram(&[(0.1, 10.0), (0.5, 30.0), (f64::INFINITY, 40.0)]);
+        let current = histogram(&[(0.1, 2.0), (0.5, 3.0), (f64::INFINITY, 4.0)]);
+        assert_eq!(current.since(&previous), current);
+    }
+}
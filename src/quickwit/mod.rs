@@ -1,46 +1,453 @@
 pub mod cluster;
+pub mod config;
+pub mod delete_task;
+pub mod elastic;
+pub mod in_flight;
 pub mod index;
 pub mod indexing;
+pub mod ingest;
+pub mod inspector;
+pub mod metrics;
 pub mod query;
 
 use crate::quickwit::{
     index::{IndexDescription, ListSplitsResponse, Split},
     query::QueryResponse,
 };
-use cluster::Cluster;
+use chrono::Utc;
+use cluster::{Cluster, ClusterNode, NodeHealth, VersionInfo};
+use delete_task::{DeleteQueryRequest, DeleteTask};
+use dioxus::prelude::{GlobalSignal, Readable, Signal};
 use dioxus_logger::tracing::info;
-use index::Index;
-use indexing::Indexing;
+use elastic::{ElasticMultiSearchResponse, ElasticRequest, ElasticSearchResponse};
+use futures::FutureExt;
+use index::{FileEntry, Index, IndexConfig, Source};
+use indexing::{IndexIndexingStats, IndexingServiceCounters};
+use ingest::{CommitMode, IngestResponse};
 use reqwasm::http::Request;
 use serde::de::DeserializeOwned;
 use wasm_bindgen::JsValue;
+use web_sys::{AbortController, AbortSignal};
 
 const BACKEND_URL: &str = env!("QW_BACKEND_URL");
+/// Retries of an idempotent request after a transient failure.
+const MAX_RETRIES: u32 = 3;
+const RETRY_BASE_DELAY_MS: f64 = 250.0;
+/// Health probes give up after this long, so an unresponsive node cannot
+/// hold up the others.
+const PROBE_TIMEOUT_MS: u32 = 3_000;
+
+/// Whether the last request to the backend got an answer from it.
+pub static BACKEND_REACHABLE: GlobalSignal<bool> = Signal::global(|| true);
 
 pub struct QuickwitApi;
 
+#[derive(Clone)]
+struct RawResponse {
+    status: u16,
+    ok: bool,
+    text: String,
+}
+
+/// Network failures and the statuses a gateway answers while the backend
+/// restarts or is overloaded.
+fn is_transient(result: &anyhow::Result<RawResponse>) -> bool {
+    match result {
+        Ok(response) => matches!(response.status, 502..=504),
+        Err(e) => !in_flight::is_cancelled(e),
+    }
+}
+
+/// Exponential delay before retry `attempt`, counted from 1, jittered so
+/// clients that failed together do not retry together.
+async fn backoff(attempt: u32) {
+    let ceiling = RETRY_BASE_DELAY_MS * 2f64.powi(attempt as i32 - 1);
+    let delay = ceiling * (0.5 + js_sys::Math::random() / 2.0);
+    gloo_timers::future::TimeoutFuture::new(delay as u32).await;
+}
+
 impl QuickwitApi {
+    /// Sends a request and reads the whole body, recording the exchange for
+    /// the request inspector. Aborting `signal` fails it with
+    /// `in_flight::Cancelled`, even once the response has arrived.
+    async fn send(
+        method: &'static str,
+        url: &str,
+        content_type: Option<&str>,
+        body: Option<String>,
+        signal: Option<&AbortSignal>,
+    ) -> anyhow::Result<RawResponse> {
+        let mut request = match method {
+            "POST" => Request::post(url),
+            "PUT" => Request::put(url),
+            "DELETE" => Request::delete(url),
+            _ => Request::get(url),
+        };
+        if let Some(content_type) = content_type {
+            request = request.header("Content-Type", content_type);
+        }
+        if let Some(body) = &body {
+            request = request.body(body.clone());
+        }
+        request = request.abort_signal(signal);
+
+        let started_at = Utc::now().timestamp_millis();
+        let result = match request.send().await {
+            Ok(response) => match response.text().await {
+                Ok(text) => Ok(RawResponse {
+                    status: response.status(),
+                    ok: response.ok(),
+                    text,
+                }),
+                Err(e) => Err(anyhow::anyhow!(e)),
+            },
+            Err(e) => Err(anyhow::anyhow!(e)),
+        };
+        let result = match signal {
+            Some(signal) if signal.aborted() => Err(anyhow::Error::new(in_flight::Cancelled)),
+            _ => result,
+        };
+
+        // A gateway error means the proxy answered but the backend did not.
+        if url.starts_with(BACKEND_URL) && !result.as_ref().is_err_and(in_flight::is_cancelled) {
+            let reachable = result
+                .as_ref()
+                .is_ok_and(|response| !matches!(response.status, 502 | 504));
+            if *BACKEND_REACHABLE.peek() != reachable {
+                *BACKEND_REACHABLE.write() = reachable;
+            }
+        }
+
+        inspector::record(inspector::Exchange {
+            method,
+            url,
+            request_body: body.as_deref(),
+            started_at,
+            finished_at: Utc::now().timestamp_millis(),
+            status: result.as_ref().ok().map(|response| response.status),
+            response_body: result
+                .as_ref()
+                .map_or("", |response| response.text.as_str()),
+            error: result.as_ref().err().map(|e| e.to_string()),
+        });
+        result
+    }
+
+    /// Sends an idempotent request, retrying transient failures with backoff.
+    async fn send_with_retry(
+        method: &'static str,
+        url: &str,
+        content_type: Option<&str>,
+        body: Option<String>,
+        signal: Option<&AbortSignal>,
+    ) -> anyhow::Result<RawResponse> {
+        let mut attempt = 0;
+        loop {
+            let result = Self::send(method, url, content_type, body.clone(), signal).await;
+            if attempt == MAX_RETRIES || !is_transient(&result) {
+                return result;
+            }
+            attempt += 1;
+            backoff(attempt).await;
+        }
+    }
+
+    /// Identical GETs in flight share one response, retries included.
+    async fn send_get(url: &str) -> anyhow::Result<RawResponse> {
+        let owned_url = url.to_string();
+        in_flight::dedup_get(url, move || {
+            async move {
+                Self::send_with_retry("GET", &owned_url, None, None, None)
+                    .await
+                    .map_err(|e| e.to_string())
+            }
+            .boxed_local()
+        })
+        .await
+    }
+
     async fn get<T: DeserializeOwned>(path: &str) -> anyhow::Result<T> {
+        Self::get_url(&format!("{}{}", BACKEND_URL, path)).await
+    }
+
+    /// Like `get`, bypassing de-duplication when a signal may abort the request.
+    async fn get_cancellable<T: DeserializeOwned>(
+        path: &str,
+        signal: Option<&AbortSignal>,
+    ) -> anyhow::Result<T> {
         let full_url = format!("{}{}", BACKEND_URL, path);
-        Request::get(&full_url)
-            .send()
-            .await
-            .map_err(|e| anyhow::anyhow!(e))?
-            .json::<T>()
-            .await
-            .map_err(|e| anyhow::anyhow!(e))
+        match signal {
+            Some(signal) => Self::read_json(
+                Self::send_with_retry("GET", &full_url, None, None, Some(signal)).await?,
+            ),
+            None => Self::get_url(&full_url).await,
+        }
+    }
+
+    /// Like `get`, for URLs outside the configured backend such as other
+    /// cluster nodes.
+    async fn get_url<T: DeserializeOwned>(full_url: &str) -> anyhow::Result<T> {
+        Self::read_json(Self::send_get(full_url).await?)
+    }
+
+    async fn post<T: DeserializeOwned>(
+        path: &str,
+        content_type: &str,
+        body: String,
+    ) -> anyhow::Result<T> {
+        let full_url = format!("{}{}", BACKEND_URL, path);
+        Self::read_json(Self::send("POST", &full_url, Some(content_type), Some(body), None).await?)
+    }
+
+    /// Like `post`, for searches: they change nothing, so transient failures
+    /// are retried like GETs.
+    async fn post_search<T: DeserializeOwned>(
+        path: &str,
+        content_type: &str,
+        body: String,
+        signal: Option<&AbortSignal>,
+    ) -> anyhow::Result<T> {
+        let full_url = format!("{}{}", BACKEND_URL, path);
+        Self::read_json(
+            Self::send_with_retry("POST", &full_url, Some(content_type), Some(body), signal)
+                .await?,
+        )
+    }
+
+    async fn put<T: DeserializeOwned>(path: &str, body: String) -> anyhow::Result<T> {
+        let full_url = format!("{}{}", BACKEND_URL, path);
+        Self::read_json(
+            Self::send("PUT", &full_url, Some("application/json"), Some(body), None).await?,
+        )
+    }
+
+    async fn delete<T: DeserializeOwned>(path: &str) -> anyhow::Result<T> {
+        let full_url = format!("{}{}", BACKEND_URL, path);
+        Self::read_json(Self::send("DELETE", &full_url, None, None, None).await?)
+    }
+
+    /// Deserializes a response body, turning error statuses into an error
+    /// carrying Quickwit's message.
+    fn read_json<T: DeserializeOwned>(response: RawResponse) -> anyhow::Result<T> {
+        if !response.ok {
+            let status = response.status;
+            let message = serde_json::from_str::<serde_json::Value>(&response.text)
+                .ok()
+                .and_then(|body| body.get("message").or(body.get("error")).cloned())
+                .map(|message| match message {
+                    serde_json::Value::String(message) => message,
+                    other => other.to_string(),
+                })
+                .unwrap_or(response.text);
+            return Err(anyhow::anyhow!("{status}: {message}"));
+        }
+        serde_json::from_str(&response.text).map_err(|e| anyhow::anyhow!(e))
+    }
+
+    async fn get_text(path: &str) -> anyhow::Result<String> {
+        Self::get_text_url(&format!("{}{}", BACKEND_URL, path)).await
+    }
+
+    async fn get_text_url(full_url: &str) -> anyhow::Result<String> {
+        Ok(Self::send_get(full_url).await?.text)
     }
 
     pub async fn cluster() -> anyhow::Result<Cluster> {
         Self::get("/api/v1/cluster").await
     }
 
+    pub async fn version() -> anyhow::Result<VersionInfo> {
+        Self::get("/api/v1/version").await
+    }
+
+    /// REST address of a node. Other nodes are reached on their gossip
+    /// address, since Quickwit serves REST and gossip on the same port unless
+    /// configured otherwise, with the backend's scheme so an https UI does
+    /// not hit mixed-content blocking.
+    pub fn node_url(node: &ClusterNode, self_node_id: &str) -> String {
+        if node.chitchat_id.node_id == self_node_id {
+            BACKEND_URL.to_string()
+        } else {
+            let scheme = BACKEND_URL
+                .split_once("://")
+                .map_or("http", |(scheme, _)| scheme);
+            format!("{scheme}://{}", node.chitchat_id.gossip_advertise_addr)
+        }
+    }
+
+    /// Reads a JSON body whatever the status, since health endpoints answer
+    /// `false` with a 503. Not retried and aborted after `PROBE_TIMEOUT_MS`,
+    /// so a down node shows up promptly.
+    async fn probe<T: DeserializeOwned>(url: &str) -> Option<T> {
+        let controller = AbortController::new().ok()?;
+        let signal = controller.signal();
+        let _timeout =
+            gloo_timers::callback::Timeout::new(PROBE_TIMEOUT_MS, move || controller.abort());
+        let response = Self::send("GET", url, None, None, Some(&signal))
+            .await
+            .ok()?;
+        serde_json::from_str(&response.text).ok()
+    }
+
+    pub async fn node_health(node_url: &str) -> NodeHealth {
+        let livez_url = format!("{node_url}/health/livez");
+        let readyz_url = format!("{node_url}/health/readyz");
+        let version_url = format!("{node_url}/api/v1/version");
+        let (live, ready, version) = futures::join!(
+            Self::probe(&livez_url),
+            Self::probe(&readyz_url),
+            Self::probe::<VersionInfo>(&version_url),
+        );
+        NodeHealth {
+            live,
+            ready,
+            version: version.map(|info| info.build.version),
+        }
+    }
+
     pub async fn indexes() -> anyhow::Result<Vec<Index>> {
         Self::get("/api/v1/indexes").await
     }
 
-    pub async fn indexing() -> anyhow::Result<Indexing> {
-        Self::get("/api/v1/indexing").await
+    pub async fn metrics_exposition() -> anyhow::Result<metrics::Exposition> {
+        Self::get_text("/metrics")
+            .await
+            .map(|text| metrics::parse_exposition(&text))
+    }
+
+    /// Effective configuration of a node, unredacted.
+    pub async fn node_config(node_url: &str) -> anyhow::Result<serde_json::Value> {
+        Self::get_url(&format!("{node_url}/api/v1/config")).await
+    }
+
+    pub async fn node_metrics(node_url: &str) -> anyhow::Result<Vec<metrics::Sample>> {
+        Self::get_text_url(&format!("{node_url}/metrics"))
+            .await
+            .map(|text| metrics::parse(&text))
+    }
+
+    /// Pipeline counters of a node's indexing service.
+    pub async fn node_indexing_counters(node_url: &str) -> anyhow::Result<IndexingServiceCounters> {
+        Self::get_url(&format!("{node_url}/api/v1/indexing")).await
+    }
+
+    /// Indexing samples of every indexer, since each node's `/metrics` only
+    /// counts what it indexed itself. Also returns the indexers that did not
+    /// answer.
+    pub async fn cluster_indexing_samples(
+        cluster: &Cluster,
+    ) -> (Vec<metrics::Sample>, Vec<String>) {
+        let indexers = cluster
+            .nodes()
+            .into_iter()
+            .filter(|node| node.roles.iter().any(|role| role == "indexer"));
+        let scrapes = indexers.map(|node| async move {
+            let node_url = Self::node_url(&node, &cluster.self_node_id);
+            (
+                node.chitchat_id.node_id,
+                Self::node_metrics(&node_url).await,
+            )
+        });
+
+        let mut samples = Vec::new();
+        let mut unreached_nodes = Vec::new();
+        for (node_id, scraped) in futures::future::join_all(scrapes).await {
+            match scraped {
+                Ok(scraped) => samples.extend(scraped.into_iter().filter(|sample| {
+                    sample.name == indexing::PROCESSED_DOCS
+                        || sample.name == indexing::PROCESSED_BYTES
+                })),
+                Err(_) => unreached_nodes.push(node_id),
+            }
+        }
+        (samples, unreached_nodes)
+    }
+
+    pub async fn index_indexing_stats(index_id: &str) -> anyhow::Result<IndexIndexingStats> {
+        let cluster = Self::cluster().await?;
+        let (samples, unreached_nodes) = Self::cluster_indexing_samples(&cluster).await;
+        Ok(IndexIndexingStats {
+            unreached_nodes,
+            ..IndexIndexingStats::new(index_id, &samples, cluster.indexing_tasks())
+        })
+    }
+
+    pub async fn create_index(config: &IndexConfig) -> anyhow::Result<Index> {
+        let body = serde_json::to_string(config)?;
+        Self::post("/api/v1/indexes", "application/json", body).await
+    }
+
+    pub async fn update_index(index_id: &str, config: &IndexConfig) -> anyhow::Result<Index> {
+        let path = format!("/api/v1/indexes/{index_id}");
+        Self::put(&path, serde_json::to_string(config)?).await
+    }
+
+    /// Deletes an index, or with `dry_run` only lists the files that would go.
+    pub async fn delete_index(index_id: &str, dry_run: bool) -> anyhow::Result<Vec<FileEntry>> {
+        let path = format!("/api/v1/indexes/{index_id}?dry_run={dry_run}");
+        Self::delete(&path).await
+    }
+
+    pub async fn clear_index(index_id: &str) -> anyhow::Result<()> {
+        let path = format!("/api/v1/indexes/{index_id}/clear");
+        Self::put(&path, String::new()).await
+    }
+
+    pub async fn mark_splits_for_deletion(
+        index_id: &str,
+        split_ids: &[String],
+    ) -> anyhow::Result<()> {
+        let path = format!("/api/v1/indexes/{index_id}/splits/mark-for-deletion");
+        let body = serde_json::json!({ "split_ids": split_ids });
+        Self::put(&path, body.to_string()).await
+    }
+
+    pub async fn create_source(index_id: &str, source: &Source) -> anyhow::Result<Source> {
+        let path = format!("/api/v1/indexes/{index_id}/sources");
+        Self::post(&path, "application/json", serde_json::to_string(source)?).await
+    }
+
+    pub async fn toggle_source(
+        index_id: &str,
+        source_id: &str,
+        enable: bool,
+    ) -> anyhow::Result<()> {
+        let path = format!("/api/v1/indexes/{index_id}/sources/{source_id}/toggle");
+        let body = serde_json::json!({ "enable": enable });
+        Self::put(&path, body.to_string()).await
+    }
+
+    pub async fn reset_source_checkpoint(index_id: &str, source_id: &str) -> anyhow::Result<()> {
+        let path = format!("/api/v1/indexes/{index_id}/sources/{source_id}/reset-checkpoint");
+        Self::put(&path, String::new()).await
+    }
+
+    pub async fn delete_source(index_id: &str, source_id: &str) -> anyhow::Result<()> {
+        let path = format!("/api/v1/indexes/{index_id}/sources/{source_id}");
+        Self::delete(&path).await
+    }
+
+    /// Sends NDJSON documents to the ingest API.
+    pub async fn ingest(
+        index_id: &str,
+        ndjson: String,
+        commit: CommitMode,
+    ) -> anyhow::Result<IngestResponse> {
+        let path = format!("/api/v1/{index_id}/ingest?commit={}", commit.as_str());
+        Self::post(&path, "application/x-ndjson", ndjson).await
+    }
+
+    pub async fn delete_tasks(index_id: &str) -> anyhow::Result<Vec<DeleteTask>> {
+        let path = format!("/api/v1/{index_id}/delete-tasks");
+        Self::get(&path).await
+    }
+
+    pub async fn create_delete_task(
+        index_id: &str,
+        request: &DeleteQueryRequest,
+    ) -> anyhow::Result<DeleteTask> {
+        let path = format!("/api/v1/{index_id}/delete-tasks");
+        Self::post(&path, "application/json", serde_json::to_string(request)?).await
     }
 
     pub async fn index_detail(index_id: &str) -> anyhow::Result<Index> {
@@ -58,19 +465,97 @@ impl QuickwitApi {
         Self::get(&path).await
     }
 
+    pub async fn elastic_search(
+        index_id: &str,
+        body: &serde_json::Value,
+        signal: Option<&AbortSignal>,
+    ) -> anyhow::Result<ElasticSearchResponse> {
+        let path = format!("/api/v1/_elastic/{}/_search", encode_index_expr(index_id));
+        let response: ElasticSearchResponse =
+            Self::post_search(&path, "application/json", body.to_string(), signal).await?;
+        response.into_result()
+    }
+
+    pub async fn elastic_msearch(
+        default_index: &str,
+        searches: &[(serde_json::Value, serde_json::Value)],
+        signal: Option<&AbortSignal>,
+    ) -> anyhow::Result<ElasticMultiSearchResponse> {
+        let ndjson = ElasticRequest::to_ndjson(searches, default_index);
+        let response: ElasticMultiSearchResponse = Self::post_search(
+            "/api/v1/_elastic/_msearch",
+            "application/x-ndjson",
+            ndjson,
+            signal,
+        )
+        .await?;
+        if let Some(error) = response.responses.iter().find_map(|r| r.error.as_ref()) {
+            return Err(anyhow::anyhow!("{error}"));
+        }
+        Ok(response)
+    }
+
+    /// Runs a search written in Elasticsearch DSL through the `_elastic` API.
+    pub async fn elastic(
+        index_id: &str,
+        request: &ElasticRequest,
+        signal: Option<&AbortSignal>,
+    ) -> anyhow::Result<QueryResponse> {
+        match request {
+            ElasticRequest::Search(body) => Self::elastic_search(index_id, body, signal)
+                .await
+                .map(Into::into),
+            ElasticRequest::MultiSearch(searches) => {
+                Self::elastic_msearch(index_id, searches, signal)
+                    .await
+                    .map(Into::into)
+            }
+        }
+    }
+
+    /// Renders an Elasticsearch DSL search as the curl command `elastic`
+    /// would send.
+    pub fn elastic_curl(index_id: &str, request: &ElasticRequest) -> String {
+        let (url, content_type, body) = match request {
+            ElasticRequest::Search(body) => (
+                format!(
+                    "{}/api/v1/_elastic/{}/_search",
+                    BACKEND_URL,
+                    encode_index_expr(index_id)
+                ),
+                "application/json",
+                body.to_string(),
+            ),
+            ElasticRequest::MultiSearch(searches) => (
+                format!("{}/api/v1/_elastic/_msearch", BACKEND_URL),
+                "application/x-ndjson",
+                ElasticRequest::to_ndjson(searches, index_id),
+            ),
+        };
+        format!(
+            "curl -XPOST {} -H {} --data-binary {}",
+            shell_quote(&url),
+            shell_quote(&format!("Content-Type: {content_type}")),
+            shell_quote(&body)
+        )
+    }
+
     // New builder method
     pub fn query(index_id: &str) -> QueryBuilder {
         QueryBuilder::new(index_id)
     }
 }
 
+#[derive(Clone)]
 pub struct QueryBuilder<'a> {
     index_id: &'a str,
     query_str: String,
     max_hits: usize,
+    start_offset: usize,
     sort_by_field: Option<String>,
     start_timestamp: Option<String>,
     end_timestamp: Option<String>,
+    abort_signal: Option<AbortSignal>,
 }
 
 impl<'a> QueryBuilder<'a> {
@@ -79,9 +564,11 @@ impl<'a> QueryBuilder<'a> {
             index_id,
             query_str: String::new(),
             max_hits: 20, // Default value
+            start_offset: 0,
             sort_by_field: None,
             start_timestamp: None,
             end_timestamp: None,
+            abort_signal: None,
         }
     }
 
@@ -95,6 +582,11 @@ impl<'a> QueryBuilder<'a> {
         self
     }
 
+    pub fn start_offset(mut self, start_offset: usize) -> Self {
+        self.start_offset = start_offset;
+        self
+    }
+
     pub fn sort_by_field(mut self, field: &str) -> Self {
         self.sort_by_field = Some(field.to_string());
         self
@@ -116,28 +608,135 @@ impl<'a> QueryBuilder<'a> {
         self
     }
 
-    pub async fn execute(self) -> anyhow::Result<QueryResponse> {
-        let encoded_index = urlencoding::encode(self.index_id);
+    /// Lets the search be aborted, typically with a signal from
+    /// `in_flight::supersede`.
+    pub fn abort_signal(mut self, signal: AbortSignal) -> Self {
+        self.abort_signal = Some(signal);
+        self
+    }
+
+    fn non_empty(value: &Option<String>) -> Option<&str> {
+        value.as_deref().filter(|value| !value.is_empty())
+    }
+
+    /// Renders the search as a curl command against the configured backend.
+    pub fn to_curl(&self) -> String {
+        let url = format!(
+            "{}/api/v1/{}/search?{}",
+            BACKEND_URL,
+            encode_index_expr(self.index_id),
+            self.search_params()
+        );
+        format!("curl -XGET {}", shell_quote(&url))
+    }
+
+    /// Renders the search as a `quickwit index search` invocation.
+    pub fn to_cli(&self) -> String {
+        let mut command = format!(
+            "quickwit index search --index {} --query {} --max-hits {}",
+            shell_quote(self.index_id),
+            shell_quote(&self.query_str),
+            self.max_hits
+        );
+
+        if self.start_offset > 0 {
+            command.push_str(&format!(" --start-offset {}", self.start_offset));
+        }
+
+        if let Some(sort_field) = Self::non_empty(&self.sort_by_field) {
+            command.push_str(&format!(" --sort-by-field {}", shell_quote(sort_field)));
+        }
+
+        if let Some(start_ts) = Self::non_empty(&self.start_timestamp) {
+            command.push_str(&format!(" --start-timestamp {}", start_ts));
+        }
+
+        if let Some(end_ts) = Self::non_empty(&self.end_timestamp) {
+            command.push_str(&format!(" --end-timestamp {}", end_ts));
+        }
+
+        command
+    }
+
+    /// Renders the search as an Elasticsearch `_search` request body. The time
+    /// range becomes a range filter on `timestamp_field` when one is given.
+    pub fn to_elasticsearch(&self, timestamp_field: Option<&str>) -> serde_json::Value {
+        let query = if self.query_str.trim().is_empty() || self.query_str.trim() == "*" {
+            serde_json::json!({ "match_all": {} })
+        } else {
+            serde_json::json!({ "query_string": { "query": self.query_str } })
+        };
+
+        let mut range = serde_json::Map::new();
+        if let Some(start_ts) = Self::non_empty(&self.start_timestamp) {
+            range.insert("gte".to_string(), serde_json::json!(start_ts));
+        }
+        if let Some(end_ts) = Self::non_empty(&self.end_timestamp) {
+            range.insert("lt".to_string(), serde_json::json!(end_ts));
+        }
+
+        let query = match timestamp_field {
+            Some(field) if !range.is_empty() => {
+                range.insert("format".to_string(), serde_json::json!("epoch_second"));
+                serde_json::json!({
+                    "bool": {
+                        "must": [query],
+                        "filter": [{ "range": { field: range } }],
+                    }
+                })
+            }
+            _ => query,
+        };
+
+        let mut body = serde_json::json!({
+            "query": query,
+            "size": self.max_hits,
+        });
+
+        if self.start_offset > 0 {
+            body["from"] = serde_json::json!(self.start_offset);
+        }
+
+        if let Some(sort_field) = Self::non_empty(&self.sort_by_field) {
+            let (field, order) = match sort_field.strip_prefix('+') {
+                Some(field) => (field, "asc"),
+                None => (sort_field.trim_start_matches('-'), "desc"),
+            };
+            body["sort"] = serde_json::json!([{ field: { "order": order } }]);
+        }
+
+        body
+    }
+
+    fn search_params(&self) -> String {
         let encoded_query = urlencoding::encode(&self.query_str);
 
         let mut query_params = format!("query={}&max_hits={}", encoded_query, self.max_hits);
 
-        if let Some(sort_field) = &self.sort_by_field {
+        if self.start_offset > 0 {
+            query_params.push_str(&format!("&start_offset={}", self.start_offset));
+        }
+
+        if let Some(sort_field) = Self::non_empty(&self.sort_by_field) {
             let encoded_sort = urlencoding::encode(sort_field);
             query_params.push_str(&format!("&sort_by_field={}", encoded_sort));
         }
 
-        if let Some(start_ts) = &self.start_timestamp {
+        if let Some(start_ts) = Self::non_empty(&self.start_timestamp) {
             query_params.push_str(&format!("&start_timestamp={}", start_ts));
         }
 
-        if let Some(end_ts) = &self.end_timestamp {
+        if let Some(end_ts) = Self::non_empty(&self.end_timestamp) {
             query_params.push_str(&format!("&end_timestamp={}", end_ts));
         }
 
-        let path = format!("/api/v1/{}/search?{}", encoded_index, query_params);
+        query_params
+    }
 
-        info!("{path}");
+    /// Records the search in the browser URL so it survives a reload.
+    pub fn push_history(&self) {
+        let encoded_index = encode_index_expr(self.index_id);
+        let query_params = self.search_params();
 
         if let Some(win) = web_sys::window() {
             if let Ok(history) = win.history() {
@@ -145,7 +744,40 @@ impl<'a> QueryBuilder<'a> {
                 let _ = history.push_state_with_url(&JsValue::NULL, "", Some(&url));
             }
         }
+    }
 
-        QuickwitApi::get(&path).await
+    pub async fn execute(self) -> anyhow::Result<QueryResponse> {
+        self.push_history();
+        self.fetch().await
+    }
+
+    /// Runs the search without touching the browser URL, for background polling.
+    pub async fn fetch(self) -> anyhow::Result<QueryResponse> {
+        let encoded_index = encode_index_expr(self.index_id);
+        let path = format!("/api/v1/{}/search?{}", encoded_index, self.search_params());
+
+        info!("{path}");
+
+        QuickwitApi::get_cancellable(&path, self.abort_signal.as_ref()).await
     }
 }
+
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+/// Percent-encodes an index id expression while keeping the `,` and `*` that
+/// Quickwit interprets as list separators and wildcards.
+fn encode_index_expr(index_expr: &str) -> String {
+    index_expr
+        .split(',')
+        .map(|pattern| {
+            pattern
+                .split('*')
+                .map(|part| urlencoding::encode(part).into_owned())
+                .collect::<Vec<_>>()
+                .join("*")
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
@@ -3,4 +3,7 @@ pub struct QueryResponse {
     pub elapsed_time_micros: f64,
     pub hits: Vec<serde_json::Value>,
     pub num_hits: i64,
+    /// Index each hit came from, when the search spanned several indexes.
+    #[serde(skip)]
+    pub hit_indexes: Vec<String>,
 }
@@ -0,0 +1,223 @@
+use crate::create_index::{Checkbox, INPUT_STYLE};
+use crate::indexes::format_bytes;
+use crate::query_editor::copy_to_clipboard;
+use crate::quickwit::inspector::{RECORDED, RecordedRequest};
+use chrono::{TimeZone, Utc};
+use dioxus::prelude::*;
+
+pub static INSPECTOR_OPEN: GlobalSignal<bool> = Signal::global(|| false);
+
+const BUTTON_STYLE: &str = "border: 1px solid #404040; border-radius: 4px; padding: 2px 10px; color: #f8f9fa; background: none; cursor: pointer; font-size: 12px;";
+
+fn format_time(millis: i64) -> String {
+    Utc.timestamp_millis_opt(millis)
+        .single()
+        .map(|at| at.format("%H:%M:%S%.3f").to_string())
+        .unwrap_or_default()
+}
+
+/// Pretty-prints JSON bodies, leaving anything else as is.
+fn format_body(body: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(body)
+        .ok()
+        .and_then(|value| serde_json::to_string_pretty(&value).ok())
+        .unwrap_or_else(|| body.to_string())
+}
+
+fn row_background(is_selected: bool) -> &'static str {
+    if is_selected {
+        "#2a2a2a"
+    } else {
+        "transparent"
+    }
+}
+
+fn matches_filter(request: &RecordedRequest, needle: &str) -> bool {
+    needle.is_empty()
+        || request.url.to_lowercase().contains(needle)
+        || request.method.to_lowercase().contains(needle)
+        || request
+            .status
+            .is_some_and(|status| status.to_string().contains(needle))
+}
+
+/// Header button toggling the inspector, showing how many recorded requests
+/// failed.
+#[component]
+pub fn InspectorToggle() -> Element {
+    let num_errors = RECORDED
+        .read()
+        .iter()
+        .filter(|request| request.is_error())
+        .count();
+
+    rsx! {
+        button {
+            r#type: "button",
+            style: "{BUTTON_STYLE} font-weight: 400;",
+            onclick: move |_| *INSPECTOR_OPEN.write() ^= true,
+            "Requests"
+            if num_errors > 0 {
+                span { color: "#e57373", " ({num_errors} failed)" }
+            }
+        }
+    }
+}
+
+#[component]
+fn RequestDetail(request: RecordedRequest) -> Element {
+    let response_body = format_body(&request.response_body);
+    let request_body = request.request_body.as_deref().map(format_body);
+
+    rsx! {
+        div { flex: 1, overflow_y: "auto", padding: "8px 12px", border_left: "1px solid #404040", font_size: "12px",
+            div { display: "flex", gap: "8px", margin_bottom: "8px",
+                button {
+                    r#type: "button",
+                    style: BUTTON_STYLE,
+                    onclick: {
+                        let url = request.url.clone();
+                        move |_| copy_to_clipboard(url.clone())
+                    },
+                    "COPY URL"
+                }
+                button {
+                    r#type: "button",
+                    style: BUTTON_STYLE,
+                    onclick: {
+                        let curl = request.to_curl();
+                        move |_| copy_to_clipboard(curl.clone())
+                    },
+                    "COPY AS CURL"
+                }
+                button {
+                    r#type: "button",
+                    style: BUTTON_STYLE,
+                    onclick: {
+                        let body = request.response_body.clone();
+                        move |_| copy_to_clipboard(body.clone())
+                    },
+                    "COPY RESPONSE"
+                }
+            }
+            p { color: "#f8f9fa", word_break: "break-all", "{request.method} {request.url}" }
+            if let Some(error) = &request.error {
+                p { color: "#e57373", margin_top: "4px", "{error}" }
+            }
+            if let Some(body) = request_body {
+                p { color: "#b0b0b0", margin_top: "8px",
+                    "Request body ({format_bytes(request.request_size as u64)})"
+                }
+                pre { style: "white-space: pre-wrap; word-break: break-all; color: #f8f9fa; background: #181a1b; padding: 6px; margin-top: 4px;",
+                    "{body}"
+                }
+            }
+            p { color: "#b0b0b0", margin_top: "8px",
+                "Response body ({format_bytes(request.response_size as u64)})"
+            }
+            pre { style: "white-space: pre-wrap; word-break: break-all; color: #f8f9fa; background: #181a1b; padding: 6px; margin-top: 4px;",
+                "{response_body}"
+            }
+        }
+    }
+}
+
+/// Docked panel listing every request sent by `QuickwitApi`, newest first.
+#[component]
+pub fn RequestInspector() -> Element {
+    let mut filter = use_signal(String::new);
+    let mut errors_only = use_signal(|| false);
+    let mut selected: Signal<Option<u64>> = use_signal(|| None);
+
+    if !INSPECTOR_OPEN() {
+        return rsx! {};
+    }
+
+    let needle = filter().to_lowercase();
+    let requests: Vec<RecordedRequest> = RECORDED
+        .read()
+        .iter()
+        .rev()
+        .filter(|request| matches_filter(request, &needle))
+        .filter(|request| !errors_only() || request.is_error())
+        .cloned()
+        .collect();
+    let selected_request =
+        selected().and_then(|id| requests.iter().find(|request| request.id == id).cloned());
+
+    rsx! {
+        div {
+            height: "40vh",
+            display: "flex",
+            flex_direction: "column",
+            border_top: "1px solid #404040",
+            background: "#1e1e1e",
+            div { display: "flex", align_items: "center", gap: "12px", padding: "6px 12px", border_bottom: "1px solid #404040",
+                span { color: "#f8f9fa", font_size: "13px", font_weight: "600", "Requests" }
+                input {
+                    style: "{INPUT_STYLE} width: 260px;",
+                    placeholder: "Filter by URL, method or status",
+                    value: "{filter}",
+                    oninput: move |e| filter.set(e.value()),
+                }
+                Checkbox {
+                    label: "errors only",
+                    checked: errors_only(),
+                    onchange: move |checked| errors_only.set(checked),
+                }
+                span { color: "#5F6060", font_size: "12px", "{requests.len()} shown" }
+                div { flex: 1 }
+                button {
+                    r#type: "button",
+                    style: BUTTON_STYLE,
+                    onclick: move |_| {
+                        RECORDED.write().clear();
+                        selected.set(None);
+                    },
+                    "CLEAR"
+                }
+                button {
+                    r#type: "button",
+                    style: BUTTON_STYLE,
+                    onclick: move |_| *INSPECTOR_OPEN.write() = false,
+                    "CLOSE"
+                }
+            }
+            div { display: "flex", flex: 1, min_height: 0,
+                div { flex: 1, overflow_y: "auto",
+                    table { style: "width: 100%; border-collapse: collapse; font-size: 12px;",
+                        tbody {
+                            for request in requests {
+                                tr {
+                                    key: "{request.id}",
+                                    style: "border-bottom: 1px solid #2a2a2a; cursor: pointer; background: {row_background(selected() == Some(request.id))};",
+                                    onclick: move |_| selected.set(Some(request.id)),
+                                    td { style: "padding: 3px 8px; color: #5F6060; white-space: nowrap;",
+                                        "{format_time(request.started_at)}"
+                                    }
+                                    td { style: "padding: 3px 8px; color: #f8f9fa;", "{request.method}" }
+                                    td {
+                                        style: if request.is_error() { "padding: 3px 8px; color: #e57373;" } else { "padding: 3px 8px; color: #81c784;" },
+                                        {request.status.map_or_else(|| "failed".to_string(), |status| status.to_string())}
+                                    }
+                                    td { style: "padding: 3px 8px; color: #b0b0b0; white-space: nowrap;",
+                                        "{request.latency_ms} ms"
+                                    }
+                                    td { style: "padding: 3px 8px; color: #b0b0b0; white-space: nowrap;",
+                                        "{format_bytes(request.response_size as u64)}"
+                                    }
+                                    td { style: "padding: 3px 8px; color: #f8f9fa; word-break: break-all;",
+                                        "{request.url}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                if let Some(request) = selected_request {
+                    RequestDetail { key: "{request.id}", request }
+                }
+            }
+        }
+    }
+}